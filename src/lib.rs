@@ -9,12 +9,48 @@ use std::ops;
 use num::pow::*;
 use serde::{Serialize, Deserialize};
 
+/// Errors that may happen when operating over matrices.
 #[derive(Debug, PartialEq)]
 pub enum MatxError {
-    SizeError,
+    /// Shapes are not compatible; holds the two (rows, cols) shapes involved, the left-hand (or expected) one first.
+    SizeError((usize, usize), (usize, usize)),
+    /// Input data could not be read or parsed into a matrix; holds a description of the problem.
+    ParseError(String),
+    /// A row or column index is out of range; holds the faulty index and the size of the dimension it was used on.
+    IndexError(usize, usize),
+    /// The matrix is singular (not invertible) while the operation requires it not to be.
+    SingularError,
+    /// The matrix is not positive-definite while the operation requires it to be.
+    DefinitenessError,
+    /// An arithmetic operation overflowed the element type.
+    OverflowError,
+    /// The inner dimensions of a matrix product do not match; holds the number of columns of the left-hand matrix and the number of rows of the right-hand one.
+    DimensionMismatch(usize, usize),
+    /// A division by zero was attempted.
+    DivideByZero,
 
 }
 
+/// Kinds of matrix norms available through `Matrix::norm()`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NormKind {
+    /// Square root of the sum of the squares of all cells.
+    Frobenius,
+    /// Maximum absolute column sum.
+    One,
+    /// Maximum absolute row sum.
+    Inf,
+}
+
+/// Output sizes available for `Matrix::convolve2d()`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ConvMode {
+    /// Only positions where the kernel fits entirely inside the matrix: (rows - kernel.rows + 1)*(cols - kernel.cols + 1).
+    Valid,
+    /// Same dimensions as the input, the matrix being padded with zeros.
+    Same,
+}
+
 
 /// Structure that defines a matrix. It only has two properties, a vector of values of type T that is segmented virtually when operating over the matrix, and the number of rows and columns.
 #[derive(PartialEq, Debug, Default, Clone, Serialize, Deserialize)]
@@ -27,20 +63,57 @@ pub struct Matrix<T> {
 
 impl<T: Debug> Display for Matrix<T> {
 
+    /// Writes the matrix one row per line, cells being right-aligned on the widest cell of their column.
+    /// The formatter's precision is passed on to each cell (`{:.2}` rounds floats to two decimals), and its width sets a minimum width for every cell.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        
+
+        let cells: Vec<String> = self.data.iter().map(|x| match f.precision() {
+            Some(p) => format!("{:.*?}", p, x),
+            None => format!("{:?}", x)
+        }).collect();
+
+        let widths: Vec<usize> = (0..self.cols).map(|j| {
+            (0..self.rows).map(|i| cells[i*self.cols+j].chars().count()).fold(f.width().unwrap_or(0), usize::max)
+        }).collect();
+
         for i in 0..self.rows {
-            for j in 0..self.cols {
-                write!(f, "\t{:?}", self.data[i*self.cols+j]).unwrap();
+            for (j, width) in widths.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "{:>width$}", cells[i*self.cols+j], width = width)?;
             }
 
-            writeln!(f).unwrap();
+            writeln!(f)?;
         }
 
         Ok(())
     }
 }
 
+impl<T: Debug> Matrix<T> {
+
+    /// Method that renders the matrix like `Display` does (aligned columns, one row per line), each row being surrounded with brackets. Handy for logging.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![
+    ///     vec![1, 20], 
+    ///     vec![300, 4]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.to_pretty_string(), "[   1 20 ]\n[ 300  4 ]\n");
+    /// ```
+    pub fn to_pretty_string(&self) -> String {
+        self.to_string().lines().map(|line| format!("[ {} ]\n", line)).collect()
+    }
+}
+
 impl<T: num::NumCast + Clone> Matrix<T> {
     
     /// Constructor of a new, empty matrix of numbers of size rows*cols. Every value is initialized using zeros.
@@ -63,11 +136,8 @@ impl<T: num::NumCast + Clone> Matrix<T> {
             cols
         }
     }
-}
 
-impl<T: Default + Clone> Matrix<T> {
-    
-    /// Constructor of a new, empty matrix of numbers of size rows*cols. Every value is initialized using zeros.
+    /// Constructor of a new matrix of numbers of size rows*cols. Every value is initialized using ones.
     /// 
     /// # Examples
     /// 
@@ -75,33 +145,261 @@ impl<T: Default + Clone> Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// let mat = Matrix::<f64>::new(2, 2);
+    /// let mat = Matrix::<f64>::ones(2, 2);
     /// // Gives: 
-    /// // 0.0f64, 0.0f64
-    /// // 0.0f64, 0.0f64
+    /// // 1.0f64, 1.0f64
+    /// // 1.0f64, 1.0f64
     /// ```
-    pub fn empty(rows: usize, cols: usize) -> Self {
+    pub fn ones(rows: usize, cols: usize) -> Self {
         Self {
-            data: vec![T::default(); rows*cols],
+            data: vec![num::NumCast::from(1).unwrap(); rows*cols],
             rows,
             cols
         }
     }
-}
 
-impl<T: Clone> Matrix<T> {
+    /// Constructor of a new matrix of numbers with the same dimensions as `other`, initialized using zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mask = Matrix::from(vec![vec![true, false, true]]);
+    /// 
+    /// let mat = Matrix::<f64>::zeros_like(&mask);
+    /// 
+    /// assert_eq!(mat, Matrix::<f64>::new(1, 3));
+    /// ```
+    pub fn zeros_like<U>(other: &Matrix<U>) -> Self {
+        Self::new(other.rows, other.cols)
+    }
 
-    pub fn apply<F>(&self, f: F) -> Self 
-    where F: FnMut(&T,) -> T
+    /// Constructor of a new matrix of numbers with the same dimensions as `other`, initialized using ones.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::ones_like(&Matrix::<f64>::new(2, 3));
+    /// 
+    /// assert_eq!(mat, Matrix::<i32>::ones(2, 3));
+    /// ```
+    pub fn ones_like<U>(other: &Matrix<U>) -> Self {
+        Self::ones(other.rows, other.cols)
+    }
+
+    /// Method that converts the matrix into a matrix of another numeric type, or returns `None` if a value cannot be represented in the new type.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![vec![1, 300]]);
+    /// 
+    /// assert_eq!(mat.cast::<f64>(), Some(Matrix::from(vec![vec![1.0f64, 300.0f64]])));
+    /// assert_eq!(mat.cast::<u8>(), None);
+    /// ```
+    pub fn cast<U: num::NumCast>(&self) -> Option<Matrix<U>> {
+        Some(Matrix::<U> {
+            data: self.data.iter().map(|x| U::from(x.clone())).collect::<Option<Vec<U>>>()?,
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+
+    /// Method that computes a norm of the matrix, as a `f64`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![
+    ///     vec![3, 0], 
+    ///     vec![-4, 0]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.norm(NormKind::Frobenius), 5.0f64);
+    /// assert_eq!(mat.norm(NormKind::One), 7.0f64);
+    /// assert_eq!(mat.norm(NormKind::Inf), 4.0f64);
+    /// ```
+    pub fn norm(&self, kind: NormKind) -> f64 {
+
+        let abs = |i: usize, j: usize| self.data[i*self.cols+j].to_f64().unwrap().abs();
+
+        match kind {
+            NormKind::Frobenius => self.data.iter().map(|x| x.to_f64().unwrap().powi(2)).sum::<f64>().sqrt(),
+            NormKind::One => (0..self.cols).map(|j| (0..self.rows).map(|i| abs(i, j)).sum::<f64>()).fold(0.0f64, f64::max),
+            NormKind::Inf => (0..self.rows).map(|i| (0..self.cols).map(|j| abs(i, j)).sum::<f64>()).fold(0.0f64, f64::max),
+        }
+    }
+
+    /// Method that computes the rank of the matrix by reducing it to row echelon form (with partial pivoting), values whose magnitude is at most `epsilon` being taken as zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![
+    ///     vec![1, 2, 3], 
+    ///     vec![2, 4, 6]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.rank(1e-10), 1);
+    /// ```
+    pub fn rank(&self, epsilon: f64) -> usize {
+
+        let mut m: Vec<f64> = self.data.iter().map(|x| x.to_f64().unwrap()).collect();
+        let mut rank = 0;
+
+        for j in 0..self.cols {
+
+            if rank == self.rows {
+                break;
+            }
+
+            let pivot = (rank..self.rows).fold(rank, |p, i| if m[i*self.cols+j].abs() > m[p*self.cols+j].abs() { i } else { p });
+
+            if m[pivot*self.cols+j].abs() <= epsilon {
+                continue;
+            }
+
+            for k in 0..self.cols {
+                m.swap(rank*self.cols+k, pivot*self.cols+k);
+            }
+
+            for i in rank+1..self.rows {
+                let factor = m[i*self.cols+j] / m[rank*self.cols+j];
+
+                for k in j..self.cols {
+                    m[i*self.cols+k] -= factor * m[rank*self.cols+k];
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// Method that downsamples the matrix by average-pooling: each cell of the output is the mean of a `window`*`window` block, as a `f64`. Blocks are laid out like with `.max_pool()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64, 0.5f64], 
+    ///     vec![1.2f64, 0.2f64, 9.0f64]
+    /// ]);
+    /// 
+    /// assert!(mat.avg_pool(2, 2).approx_eq(&Matrix::from(vec![vec![1.75f64]]), 1e-12));
+    /// ```
+    pub fn avg_pool(&self, window: usize, stride: usize) -> Matrix<f64> {
+        let size = window.max(1).pow(2) as f64;
+        self.pool(window, stride, |cells| cells.map(|x| x.to_f64().unwrap()).sum::<f64>() / size)
+    }
+
+    /// Method that tells whether the matrix is an identity matrix up to `epsilon`: square, with diagonal cells within `epsilon` of 1 and all other cells within `epsilon` of 0.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let q = Matrix::<f64>::from(vec![
+    ///     vec![0.6f64, -0.8f64], 
+    ///     vec![0.8f64, 0.6f64]
+    /// ]);
+    /// 
+    /// // Rotation matrices are orthogonal
+    /// assert!((q.transpose() * q).unwrap().is_identity(1e-12));
+    /// ```
+    pub fn is_identity(&self, epsilon: f64) -> bool {
+
+        if !self.is_square() {
+            return false;
+        }
+
+        self.enumerate().all(|((i, j), x)| {
+            let target = if i == j { 1.0f64 } else { 0.0f64 };
+            (x.to_f64().unwrap() - target).abs() <= epsilon
+        })
+    }
+
+    // Matrix of `f` applied to each cell converted to `f64`; cells that cannot be converted give `NaN`
+    fn map_f64<F>(&self, f: F) -> Matrix<f64>
+    where F: Fn(f64) -> f64
     {
-        Self {
-            data: self.data.iter().map(f).collect(),
+        Matrix::<f64> {
+            data: self.data.iter().map(|x| x.to_f64().map_or(f64::NAN, &f)).collect(),
             rows: self.rows,
             cols: self.cols
         }
     }
 
-    /// Method that returns a `Rows` object, an iterator that iterates over rows of a matrix.
+    /// Method that returns a new matrix of `f64` holding the square root of each cell. Negative cells give `NaN`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![4.0f64, 2.25f64]]);
+    /// 
+    /// assert_eq!(mat.sqrt(), Matrix::from(vec![vec![2.0f64, 1.5f64]]));
+    /// ```
+    pub fn sqrt(&self) -> Matrix<f64> {
+        self.map_f64(f64::sqrt)
+    }
+
+    /// Method that returns a new matrix of `f64` holding the exponential of each cell.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![0.0f64, 1.0f64]]);
+    /// 
+    /// assert_eq!(mat.exp(), Matrix::from(vec![vec![1.0f64, std::f64::consts::E]]));
+    /// ```
+    pub fn exp(&self) -> Matrix<f64> {
+        self.map_f64(f64::exp)
+    }
+
+    /// Method that returns a new matrix of `f64` holding the natural logarithm of each cell. Zeros give negative infinity, and negative cells `NaN`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![1.0f64, std::f64::consts::E]]);
+    /// 
+    /// assert_eq!(mat.ln(), Matrix::from(vec![vec![0.0f64, 1.0f64]]));
+    /// ```
+    pub fn ln(&self) -> Matrix<f64> {
+        self.map_f64(f64::ln)
+    }
+
+    /// Method that applies the softmax function on each row independently, as a matrix of `f64` where every row sums up to 1.
+    /// The maximum of each row is subtracted before exponentiating, so that big values do not overflow.
     /// 
     /// # Examples
     /// 
@@ -110,19 +408,42 @@ impl<T: Clone> Matrix<T> {
     /// use matx::*;
     /// 
     /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
+    ///     vec![1000.0f64, 1000.0f64], 
+    ///     vec![0.0f64, 2.0f64.ln()]
     /// ]);
     /// 
-    /// for r in mat.rows() {
-    ///     println!("{:?}", r);
-    /// }
+    /// let probs = mat.softmax_rows();
+    /// 
+    /// assert!(probs.approx_eq(&Matrix::from(vec![vec![0.5f64, 0.5f64], vec![1.0f64 / 3.0f64, 2.0f64 / 3.0f64]]), 1e-12));
     /// ```
-    pub fn rows<'a>(&'a self) -> Rows<'a, T> {
-        Rows::<'a>(self, 0, 0)
+    pub fn softmax_rows(&self) -> Matrix<f64> {
+
+        let mut out = Matrix::<f64>::new(self.rows, self.cols);
+
+        for i in 0..self.rows {
+            let row = &mut out.data[i*self.cols..(i+1)*self.cols];
+
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = self.data[i*self.cols+j].to_f64().unwrap();
+            }
+
+            let max = row.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+            for x in row.iter_mut() {
+                *x = (*x - max).exp();
+            }
+
+            let sum: f64 = row.iter().sum();
+
+            for x in row.iter_mut() {
+                *x /= sum;
+            }
+        }
+
+        out
     }
 
-    /// Method that returns a `Columns` object, an iterator that iterates over columns of a matrix.
+    /// Method that divides each row by its Euclidean (L2) norm, as a matrix of `f64` where every row has a norm of 1. Rows of zeros are left unchanged.
     /// 
     /// # Examples
     /// 
@@ -131,19 +452,26 @@ impl<T: Clone> Matrix<T> {
     /// use matx::*;
     /// 
     /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
+    ///     vec![3.0f64, 4.0f64], 
+    ///     vec![0.0f64, 0.0f64]
     /// ]);
     /// 
-    /// for r in mat.cols() {
-    ///     println!("{:?}", r);
-    /// }
+    /// assert_eq!(mat.normalize_rows_l2(), Matrix::from(vec![vec![0.6f64, 0.8f64], vec![0.0f64, 0.0f64]]));
     /// ```
-    pub fn cols<'a>(&'a self) -> Columns<'a, T> {
-        Columns::<'a>(self, 0, 0)
+    pub fn normalize_rows_l2(&self) -> Matrix<f64> {
+        self.cast::<f64>().unwrap().map_rows(|row| {
+            let norm = row.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+
+            if norm == 0.0f64 {
+                row.to_vec()
+            }
+            else {
+                row.iter().map(|x| x / norm).collect()
+            }
+        })
     }
 
-    /// Method that returns a `Cells` object, an iterator that iterates over cells of a matrix.
+    /// Method that returns the population variance of each column (the mean of squared deviations from the column mean, divided by the number of rows). A matrix without rows gives `NaN` for every column.
     /// 
     /// # Examples
     /// 
@@ -152,19 +480,24 @@ impl<T: Clone> Matrix<T> {
     /// use matx::*;
     /// 
     /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
+    ///     vec![1.0f64, 5.0f64], 
+    ///     vec![3.0f64, 5.0f64]
     /// ]);
     /// 
-    /// for r in mat.iter() {
-    ///     println!("{:?}", r);
-    /// }
+    /// assert_eq!(mat.col_variance(), vec![1.0f64, 0.0f64]);
     /// ```
-    pub fn iter<'a>(&'a self) -> Cells<'a, T> {
-        Cells::<'a>(self, self.data.iter())
+    pub fn col_variance(&self) -> Vec<f64> {
+
+        let n = self.rows as f64;
+        let value = |i: usize, j: usize| self.data[i*self.cols+j].to_f64().unwrap();
+
+        (0..self.cols).map(|j| {
+            let mean = (0..self.rows).map(|i| value(i, j)).sum::<f64>() / n;
+            (0..self.rows).map(|i| (value(i, j) - mean).powi(2)).sum::<f64>() / n
+        }).collect()
     }
 
-    /// Method to get the [row ; column] item of the matrix.
+    /// Method that returns the population standard deviation of each column, that is the square root of `.col_variance()`. A matrix without rows gives `NaN` for every column.
     /// 
     /// # Examples
     /// 
@@ -172,27 +505,3591 @@ impl<T: Clone> Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// // Building the matrix
     /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
+    ///     vec![1.0f64, 5.0f64], 
+    ///     vec![5.0f64, 5.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.col_std(), vec![2.0f64, 0.0f64]);
+    /// ```
+    pub fn col_std(&self) -> Vec<f64> {
+        self.col_variance().into_iter().map(f64::sqrt).collect()
+    }
+
+    /// Method that standardizes each column (z-score): the column mean is subtracted from every cell, which is then divided by the column's standard deviation, giving columns of mean 0 and standard deviation 1.
+    /// Columns with a standard deviation of zero are left unchanged. A matrix without rows gives an empty matrix of `f64` with the same number of columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 5.0f64], 
+    ///     vec![5.0f64, 5.0f64]
     /// ]);
     /// 
-    /// let cell: f64 = mat.get(0, 1).unwrap();
-    /// assert_eq!(cell, 3.6f64);
+    /// assert_eq!(mat.standardize_cols(), Matrix::from(vec![vec![-1.0f64, 5.0f64], vec![1.0f64, 5.0f64]]));
+    /// ```
+    pub fn standardize_cols(&self) -> Matrix<f64> {
+
+        let stds = self.col_std();
+        let mut out = self.cast::<f64>().unwrap();
+
+        for (j, std) in stds.into_iter().enumerate() {
+
+            if std == 0.0f64 {
+                continue;
+            }
+
+            let mean = (0..self.rows).map(|i| out.data[i*self.cols+j]).sum::<f64>() / self.rows as f64;
+
+            for i in 0..self.rows {
+                out.data[i*self.cols+j] = (out.data[i*self.cols+j] - mean) / std;
+            }
+        }
+
+        out
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    
+    /// Constructor of a new, empty matrix of numbers of size rows*cols. Every value is initialized using zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::new(2, 2);
+    /// // Gives: 
+    /// // 0.0f64, 0.0f64
+    /// // 0.0f64, 0.0f64
+    /// ```
+    pub fn empty(rows: usize, cols: usize) -> Self {
+        Self {
+            data: vec![T::default(); rows*cols],
+            rows,
+            cols
+        }
+    }
+
+    /// Constructor of a new matrix of size rows*cols filled with default values, whose storage has room for exactly rows*cols cells: it can then be `.fill()`ed or `.set()` cell by cell without reallocating.
+    /// Use `.push_row()` to grow the matrix afterwards.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::with_capacity(2, 2);
+    /// 
+    /// mat.set(3.6f64, 0, 1).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![0.0f64, 3.6f64], vec![0.0f64, 0.0f64]]));
+    /// ```
+    pub fn with_capacity(rows: usize, cols: usize) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(rows*cols);
+        data.resize(rows*cols, T::default());
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+
+    /// Constructor of a new matrix of size rows*cols that takes ownership of a flat vector of cells, read row after row: its length must be rows*cols.
+    /// Otherwise, the error holds the expected shape and `(1, len)`, `len` being the length of the vector.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::try_new(2, 2, vec![2.0f64, 3.6f64, 1.2f64, 0.2f64]).unwrap();
+    /// // Gives: 
+    /// // 2.0f64, 3.6f64
+    /// // 1.2f64, 0.2f64
+    /// 
+    /// assert_eq!(Matrix::try_new(2, 3, vec![1.0f64; 4]), Err(MatxError::SizeError((2, 3), (1, 4))));
+    /// ```
+    pub fn try_new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, MatxError> {
+        if data.len() != rows*cols {
+            Err(MatxError::SizeError((rows, cols), (1, data.len())))
+        }
+        else {
+            Ok(Self {
+                data,
+                rows,
+                cols
+            })
+        }
+    }
+
+    /// Method that appends `row` at the bottom of the matrix. The row must have as many cells as the matrix has columns, unless the matrix is 0*0, in which case `row` sets the number of columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::new(0, 0);
+    /// 
+    /// mat.push_row(vec![2.0f64, 3.6f64]).unwrap();
+    /// 
+    /// assert_eq!(mat.push_row(vec![1.2f64]), Err(MatxError::SizeError((1, 2), (1, 1))));
+    /// ```
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), MatxError> {
+        self.insert_row(self.rows, row)
+    }
+
+    /// Constructor of a new matrix out of an iterator of rows, each row being itself an iterator of cells: cells are pushed as they come, with no intermediate `Vec<Vec<T>>`.
+    /// The number of columns is given by the first row; every other row must have the same size. An empty iterator gives an empty 0*0 matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Multiplication table
+    /// let mat = Matrix::from_rows((1..=3).map(|i| (1..=4).map(move |j| i * j))).unwrap();
+    /// 
+    /// assert_eq!(mat.row(2).unwrap(), vec![3, 6, 9, 12]);
+    /// 
+    /// assert_eq!(Matrix::from_rows(vec![vec![1, 2], vec![3]]), Err(MatxError::SizeError((1, 2), (1, 1))));
+    /// ```
+    pub fn from_rows<I, R>(iter: I) -> Result<Self, MatxError>
+    where I: IntoIterator<Item = R>, R: IntoIterator<Item = T>
+    {
+        let mut data = Vec::<T>::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for row in iter {
+
+            let start = data.len();
+            data.extend(row);
+
+            if rows == 0 {
+                cols = data.len();
+            }
+            else if data.len() - start != cols {
+                return Err(MatxError::SizeError((1, cols), (1, data.len() - start)));
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self {
+            data,
+            rows,
+            cols
+        })
+    }
+
+    /// Method that checks the shape of the matrix, returning the matrix itself if it matches `rows`*`cols` so that calls can be chained.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::new(2, 3);
+    /// 
+    /// assert!(mat.expect_shape(2, 3).is_ok());
+    /// assert_eq!(mat.expect_shape(3, 3), Err(MatxError::SizeError((3, 3), (2, 3))));
+    /// ```
+    pub fn expect_shape(&self, rows: usize, cols: usize) -> Result<&Self, MatxError> {
+        if self.rows != rows || self.cols != cols {
+            Err(MatxError::SizeError((rows, cols), (self.rows, self.cols)))
+        }
+        else {
+            Ok(self)
+        }
+    }
+
+    /// Method that tells whether two matrices have the same dimensions, as required by cell-wise operations like `+` or `-`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::new(2, 3);
+    /// 
+    /// assert!(a.same_shape(&Matrix::<f64>::ones(2, 3)));
+    /// assert!(!a.same_shape(&Matrix::<f64>::new(3, 2)));
+    /// ```
+    pub fn same_shape<U>(&self, other: &Matrix<U>) -> bool {
+        self.rows == other.rows && self.cols == other.cols
+    }
+
+    /// Method that tells whether the matrix has as many rows as columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// assert!(Matrix::<f64>::new(3, 3).is_square());
+    /// assert!(!Matrix::<f64>::new(2, 3).is_square());
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Method that gives a read-only view over the cells of the matrix, in row-major order, for raw access (FFI, custom kernels...).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.as_flat(), &[2.0f64, 3.6f64, 1.2f64, 0.2f64]);
+    /// ```
+    pub fn as_flat(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Method that consumes the matrix into the vector of its cells, in row-major order. This is the inverse of `Matrix::try_new()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.into_flat(), vec![2.0f64, 3.6f64, 1.2f64, 0.2f64]);
+    /// ```
+    pub fn into_flat(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Method that consumes the matrix and applies a closure on each cell, possibly changing the type of the elements. Unlike `.apply()`, cells are moved rather than cloned.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, -3.6f64], 
+    ///     vec![-1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let mask: Matrix<bool> = mat.map(|x| x > 0.0f64);
+    /// 
+    /// assert_eq!(mask.get(0, 1).unwrap(), false);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Matrix<U>
+    where F: FnMut(T) -> U
+    {
+        Matrix::<U> {
+            data: self.data.into_iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    /// Method that builds a new matrix by calling `f` on the coordinates (row, column) and the value of each cell, possibly changing the type of the elements.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::new(3, 3);
+    /// 
+    /// // Manhattan distance from the center
+    /// let dist = mat.map_indexed(|i, j, _| i.abs_diff(1) + j.abs_diff(1));
+    /// 
+    /// assert_eq!(dist.row(0).unwrap(), vec![2, 1, 2]);
+    /// ```
+    pub fn map_indexed<U, F>(&self, mut f: F) -> Matrix<U>
+    where F: FnMut(usize, usize, &T) -> U
+    {
+        Matrix::<U> {
+            data: self.enumerate().map(|((i, j), x)| f(i, j, x)).collect(),
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    /// Method that builds a new matrix by calling `f` on each pair of corresponding cells of `self` and `other`, possibly changing the type of the elements. Both matrices must have the same shape.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 4.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64]]);
+    /// 
+    /// let max = a.zip_map(&b, |x, y| x.max(*y)).unwrap();
+    /// 
+    /// assert_eq!(max, Matrix::from(vec![vec![3.0f64, 4.0f64]]));
+    /// ```
+    pub fn zip_map<U, V, F>(&self, other: &Matrix<U>, mut f: F) -> Result<Matrix<V>, MatxError>
+    where F: FnMut(&T, &U) -> V
+    {
+        if !self.same_shape(other) {
+            return Err(MatxError::SizeError((self.rows, self.cols), (other.rows, other.cols)));
+        }
+
+        Ok(Matrix::<V> {
+            data: self.data.iter().zip(other.data.iter()).map(|(x, y)| f(x, y)).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+
+    /// Method that builds a new matrix by replacing each row with the output of `f`, which is given the whole row at once. `f` must return as many cells as there are columns (panics otherwise).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 3.0f64], 
+    ///     vec![2.0f64, 2.0f64]
+    /// ]);
+    /// 
+    /// // Centering each row on its mean
+    /// let centered = mat.map_rows(|row| {
+    ///     let mean = row.iter().sum::<f64>() / row.len() as f64;
+    ///     row.iter().map(|x| x - mean).collect()
+    /// });
+    /// 
+    /// assert_eq!(centered, Matrix::from(vec![vec![-1.0f64, 1.0f64], vec![0.0f64, 0.0f64]]));
+    /// ```
+    pub fn map_rows<F>(&self, mut f: F) -> Matrix<T>
+    where F: FnMut(&[T]) -> Vec<T>
+    {
+        let mut data = Vec::<T>::with_capacity(self.data.len());
+
+        if self.cols > 0 {
+            for row in self.data.chunks(self.cols) {
+                let mapped = f(row);
+                assert!(mapped.len() == self.cols, "mapped rows must keep the same size");
+                data.extend(mapped);
+            }
+        }
+
+        Matrix::<T> {
+            data,
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    // Matrix of `f` applied to each `window`*`window` block of cells, blocks starting every `stride` rows and columns; incomplete blocks on the borders are dropped
+    fn pool<U, F>(&self, window: usize, stride: usize, mut f: F) -> Matrix<U>
+    where F: FnMut(&mut dyn Iterator<Item = &T>) -> U
+    {
+        let (window, stride) = (window.max(1), stride.max(1));
+        let rows = if self.rows >= window { (self.rows - window) / stride + 1 } else { 0 };
+        let cols = if self.cols >= window { (self.cols - window) / stride + 1 } else { 0 };
+
+        let mut data = Vec::<U>::with_capacity(rows*cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let (top, left) = (i*stride, j*stride);
+                let mut cells = (top..top+window).flat_map(|r| &self.data[r*self.cols+left..r*self.cols+left+window]);
+                data.push(f(&mut cells));
+            }
+        }
+
+        Matrix::<U> {
+            data,
+            rows,
+            cols
+        }
+    }
+
+    /// Method that returns an iterator over cells of the matrix along with their (row, column) coordinates, in row-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// for ((i, j), x) in mat.enumerate() {
+    ///     println!("[{} ; {}] = {}", i, j, x);
+    /// }
+    /// ```
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.data.iter().enumerate().map(move |(i, x)| ((i / cols, i % cols), x))
+    }
+
+    /// Method that returns an iterator over the cells of the main diagonal (where `row == column`), without copying them like `.diagonal()` does.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.diagonal_iter().sum::<f64>(), 2.2f64);
+    /// ```
+    pub fn diagonal_iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().step_by(self.cols + 1).take(self.rows.min(self.cols))
+    }
+
+    /// Method that reduces the matrix to a single value, calling `f` on an accumulator starting at `init` and each cell in row-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.0f64], 
+    ///     vec![1.5f64, 0.5f64]
+    /// ]);
+    /// 
+    /// // Product of all cells
+    /// assert_eq!(mat.fold(1.0f64, |acc, x| acc * x), 4.5f64);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where F: FnMut(B, &T) -> B
+    {
+        self.data.iter().fold(init, f)
+    }
+
+    /// Method that returns the (row, column) coordinates of every cell matching `pred`, in row-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.0f64], 
+    ///     vec![0.0f64, f64::NAN]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.positions(|x| x.is_nan()), vec![(1, 1)]);
+    /// ```
+    pub fn positions<F>(&self, pred: F) -> Vec<(usize, usize)>
+    where F: Fn(&T) -> bool
+    {
+        self.data.iter().enumerate()
+            .filter(|(_, x)| pred(x))
+            .map(|(i, _)| (i / self.cols, i % self.cols))
+            .collect()
+    }
+
+    /// Method that counts the cells matching `pred`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.0f64], 
+    ///     vec![0.0f64, 1.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.count_where(|x| *x != 0.0f64), 2);
+    /// ```
+    pub fn count_where<F>(&self, pred: F) -> usize
+    where F: Fn(&T) -> bool
+    {
+        self.data.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Method that tells whether at least one cell matches `pred`, stopping at the first match. An empty matrix gives `false`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, f64::NAN], 
+    ///     vec![0.0f64, 1.0f64]
+    /// ]);
+    /// 
+    /// assert!(mat.any(|x| x.is_nan()));
+    /// ```
+    pub fn any<F>(&self, pred: F) -> bool
+    where F: Fn(&T) -> bool
+    {
+        self.data.iter().any(pred)
+    }
+
+    /// Method that tells whether all cells match `pred`, stopping at the first mismatch. An empty matrix gives `true`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.5f64], 
+    ///     vec![0.0f64, 1.0f64]
+    /// ]);
+    /// 
+    /// assert!(mat.all(|x| *x >= 0.0f64));
+    /// ```
+    pub fn all<F>(&self, pred: F) -> bool
+    where F: Fn(&T) -> bool
+    {
+        self.data.iter().all(pred)
+    }
+
+    /// Method that swaps rows `a` and `b` of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.swap_rows(0, 1).unwrap();
+    /// 
+    /// assert_eq!(mat.get(0, 0).unwrap(), 1.2f64);
+    /// assert_eq!(mat.swap_rows(0, 2), Err(MatxError::IndexError(2, 2)));
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), MatxError> {
+
+        for index in [a, b] {
+            if index >= self.rows {
+                return Err(MatxError::IndexError(index, self.rows));
+            }
+        }
+
+        for j in 0..self.cols {
+            self.data.swap(a*self.cols+j, b*self.cols+j);
+        }
+
+        Ok(())
+    }
+
+    /// Method that swaps columns `a` and `b` of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.swap_cols(0, 1).unwrap();
+    /// 
+    /// assert_eq!(mat.get(0, 0).unwrap(), 3.6f64);
+    /// assert_eq!(mat.swap_cols(3, 0), Err(MatxError::IndexError(3, 2)));
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), MatxError> {
+
+        for index in [a, b] {
+            if index >= self.cols {
+                return Err(MatxError::IndexError(index, self.cols));
+            }
+        }
+
+        for i in 0..self.rows {
+            self.data.swap(i*self.cols+a, i*self.cols+b);
+        }
+
+        Ok(())
+    }
+
+    /// Method that swaps the cells at (row, column) coordinates `a` and `b`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.swap((0, 0), (1, 1)).unwrap();
+    /// 
+    /// assert_eq!(mat.diagonal(), vec![0.2f64, 2.0f64]);
+    /// assert_eq!(mat.swap((0, 0), (0, 2)), Err(MatxError::IndexError(2, 2)));
+    /// ```
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), MatxError> {
+
+        for (i, j) in [a, b] {
+            if i >= self.rows {
+                return Err(MatxError::IndexError(i, self.rows));
+            }
+
+            if j >= self.cols {
+                return Err(MatxError::IndexError(j, self.cols));
+            }
+        }
+
+        self.data.swap(a.0*self.cols+a.1, b.0*self.cols+b.1);
+
+        Ok(())
+    }
+
+    /// Method that inserts `row` so that it becomes row `at` of the matrix, rows below moving down by one; `at` may be the number of rows, to append the row at the bottom.
+    /// The row must have as many cells as the matrix has columns, unless the matrix is 0*0, in which case `row` sets the number of columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![vec![1.2f64, 0.2f64]]);
+    /// 
+    /// mat.insert_row(0, vec![2.0f64, 3.6f64]).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![2.0f64, 3.6f64], vec![1.2f64, 0.2f64]]));
+    /// assert_eq!(mat.insert_row(3, vec![0.0f64; 2]), Err(MatxError::IndexError(3, 2)));
+    /// ```
+    pub fn insert_row(&mut self, at: usize, row: Vec<T>) -> Result<(), MatxError> {
+
+        if at > self.rows {
+            return Err(MatxError::IndexError(at, self.rows));
+        }
+
+        if self.rows == 0 && self.cols == 0 {
+            self.cols = row.len();
+        }
+        else if row.len() != self.cols {
+            return Err(MatxError::SizeError((1, self.cols), (1, row.len())));
+        }
+
+        self.data.splice(at*self.cols..at*self.cols, row);
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    /// Method that inserts `col` so that it becomes column `at` of the matrix, columns on its right moving right by one; `at` may be the number of columns, to append the column on the right.
+    /// The column must have as many cells as the matrix has rows, unless the matrix is 0*0, in which case `col` sets the number of rows.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![vec![2.0f64], vec![1.2f64]]);
+    /// 
+    /// mat.insert_col(1, vec![3.6f64, 0.2f64]).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![2.0f64, 3.6f64], vec![1.2f64, 0.2f64]]));
+    /// assert_eq!(mat.insert_col(0, vec![0.0f64; 3]), Err(MatxError::SizeError((2, 1), (3, 1))));
+    /// ```
+    pub fn insert_col(&mut self, at: usize, col: Vec<T>) -> Result<(), MatxError> {
+
+        if at > self.cols {
+            return Err(MatxError::IndexError(at, self.cols));
+        }
+
+        if self.rows == 0 && self.cols == 0 {
+            self.rows = col.len();
+        }
+        else if col.len() != self.rows {
+            return Err(MatxError::SizeError((self.rows, 1), (col.len(), 1)));
+        }
+
+        let mut cells = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::<T>::with_capacity(self.rows*(self.cols+1));
+
+        for x in col {
+            data.extend(cells.by_ref().take(at));
+            data.push(x);
+            data.extend(cells.by_ref().take(self.cols - at));
+        }
+
+        self.data = data;
+        self.cols += 1;
+
+        Ok(())
+    }
+
+    /// Method that deletes row `i` of the matrix, rows below it moving up by one.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.remove_row(0).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![1.2f64, 0.2f64]]));
+    /// assert_eq!(mat.remove_row(1), Err(MatxError::IndexError(1, 1)));
+    /// ```
+    pub fn remove_row(&mut self, i: usize) -> Result<(), MatxError> {
+
+        if i >= self.rows {
+            return Err(MatxError::IndexError(i, self.rows));
+        }
+
+        self.data.drain(i*self.cols..(i+1)*self.cols);
+        self.rows -= 1;
+
+        Ok(())
+    }
+
+    /// Method that deletes column `j` of the matrix, columns on its right moving left by one.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.remove_col(0).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![3.6f64], vec![0.2f64]]));
+    /// assert_eq!(mat.remove_col(1), Err(MatxError::IndexError(1, 1)));
+    /// ```
+    pub fn remove_col(&mut self, j: usize) -> Result<(), MatxError> {
+
+        if j >= self.cols {
+            return Err(MatxError::IndexError(j, self.cols));
+        }
+
+        let cols = self.cols;
+        let mut index = 0;
+
+        self.data.retain(|_| {
+            index += 1;
+            (index - 1) % cols != j
+        });
+
+        self.cols -= 1;
+
+        Ok(())
+    }
+
+    /// Method that transposes a square matrix in place, swapping cells across the diagonal without allocating. See `.transpose()` for matrices of any shape.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.transpose_in_place().unwrap();
+    /// 
+    /// assert_eq!(mat.row(0).unwrap(), vec![2.0f64, 1.2f64]);
+    /// ```
+    pub fn transpose_in_place(&mut self) -> Result<(), MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        for i in 0..self.rows {
+            for j in i+1..self.cols {
+                self.data.swap(i*self.cols+j, j*self.cols+i);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> Matrix<T> {
+
+    /// Method that tells whether the matrix is symmetric, i.e. square and equal to its transpose.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![3.6f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert!(mat.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+
+        if !self.is_square() {
+            return false;
+        }
+
+        for i in 0..self.rows {
+            for j in i+1..self.cols {
+                if self.data[i*self.cols+j] != self.data[j*self.cols+i] {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Method that tells whether the matrix is equal to the transpose of `other`, without building the transpose.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64, 3.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![1.0f64], vec![2.0f64], vec![3.0f64]]);
+    /// 
+    /// assert!(a.equals_transpose(&b));
+    /// assert!(!a.equals_transpose(&a));
+    /// ```
+    pub fn equals_transpose(&self, other: &Matrix<T>) -> bool {
+
+        if self.rows != other.cols || self.cols != other.rows {
+            return false;
+        }
+
+        self.enumerate().all(|((i, j), x)| *x == other.data[j*other.cols+i])
+    }
+}
+
+impl<T: PartialEq + Clone> Matrix<T> {
+
+    /// Method that overwrites every cell equal to `from` with `to`, and returns the number of cells replaced.
+    /// As `NaN` is never equal to itself, use `.apply()` with `is_nan()` to get rid of `NaN` cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<i32>::from(vec![
+    ///     vec![-1, 3], 
+    ///     vec![4, -1]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.replace(&-1, 0), 2);
+    /// assert_eq!(mat, Matrix::from(vec![vec![0, 3], vec![4, 0]]));
+    /// ```
+    pub fn replace(&mut self, from: &T, to: T) -> usize {
+
+        let mut count = 0;
+
+        for x in self.data.iter_mut().filter(|x| *x == from) {
+            *x = to.clone();
+            count += 1;
+        }
+
+        count
+    }
+}
+
+impl<T: Eq + std::hash::Hash + Clone> Matrix<T> {
+
+    /// Method that counts how many times each distinct value appears in the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let labels = Matrix::<u8>::from(vec![
+    ///     vec![0, 1, 1], 
+    ///     vec![2, 1, 0]
+    /// ]);
+    /// 
+    /// let counts = labels.value_counts();
+    /// 
+    /// assert_eq!(counts[&1], 3);
+    /// assert_eq!(counts.get(&3), None);
+    /// ```
+    pub fn value_counts(&self) -> std::collections::HashMap<T, usize> {
+
+        let mut counts = std::collections::HashMap::<T, usize>::new();
+
+        for x in self.data.iter() {
+            *counts.entry(x.clone()).or_insert(0) += 1;
+        }
+
+        counts
+    }
+}
+
+impl<T: PartialOrd> Matrix<T> {
+
+    fn compare<F>(&self, other: &Matrix<T>, f: F) -> Result<Matrix<bool>, MatxError>
+    where F: Fn(&T, &T) -> bool
+    {
+        if !self.same_shape(other) {
+            Err(MatxError::SizeError((self.rows, self.cols), (other.rows, other.cols)))
+        }
+        else {
+            Ok(Matrix::<bool> {
+                data: zip(self.data.iter(), other.data.iter()).map(|(a, b)| f(a, b)).collect(),
+                rows: self.rows,
+                cols: self.cols
+            })
+        }
+    }
+
+    /// Method that compares two matrices cell by cell, giving a mask that is `true` where the cell of `self` is greater than the one of `other`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64, 3.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64, 1.0f64]]);
+    /// 
+    /// assert_eq!(a.gt(&b).unwrap(), Matrix::from(vec![vec![false, false, true]]));
+    /// ```
+    pub fn gt(&self, other: &Matrix<T>) -> Result<Matrix<bool>, MatxError> {
+        self.compare(other, |a, b| a > b)
+    }
+
+    /// Method that compares two matrices cell by cell, giving a mask that is `true` where the cell of `self` is less than the one of `other`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64, 3.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64, 1.0f64]]);
+    /// 
+    /// assert_eq!(a.lt(&b).unwrap(), Matrix::from(vec![vec![true, false, false]]));
+    /// ```
+    pub fn lt(&self, other: &Matrix<T>) -> Result<Matrix<bool>, MatxError> {
+        self.compare(other, |a, b| a < b)
+    }
+
+    /// Method that compares two matrices cell by cell, giving a mask that is `true` where the cell of `self` is greater than or equal to the one of `other`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64, 3.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64, 1.0f64]]);
+    /// 
+    /// assert_eq!(a.ge(&b).unwrap(), Matrix::from(vec![vec![false, true, true]]));
+    /// ```
+    pub fn ge(&self, other: &Matrix<T>) -> Result<Matrix<bool>, MatxError> {
+        self.compare(other, |a, b| a >= b)
+    }
+
+    /// Method that compares two matrices cell by cell, giving a mask that is `true` where the cell of `self` is less than or equal to the one of `other`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64, 3.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64, 1.0f64]]);
+    /// 
+    /// assert_eq!(a.le(&b).unwrap(), Matrix::from(vec![vec![true, true, false]]));
+    /// ```
+    pub fn le(&self, other: &Matrix<T>) -> Result<Matrix<bool>, MatxError> {
+        self.compare(other, |a, b| a <= b)
+    }
+}
+
+impl<T: PartialOrd + Clone> Matrix<T> {
+
+    /// Method that returns a new matrix where each cell is clamped into `[min, max]`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![-2.0f64, 0.5f64, 3.6f64]]);
+    /// 
+    /// assert_eq!(mat.clamp(0.0f64, 1.0f64), Matrix::from(vec![vec![0.0f64, 0.5f64, 1.0f64]]));
+    /// ```
+    pub fn clamp(&self, min: T, max: T) -> Self {
+        self.apply(|x| {
+            if *x < min {
+                min.clone()
+            }
+            else if *x > max {
+                max.clone()
+            }
+            else {
+                x.clone()
+            }
+        })
+    }
+
+    /// Method that returns a new matrix holding, for each cell, the biggest value between `self` and `other`, which must have the same shape. On ties or incomparable values (`NaN`), the cell of `self` is kept.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 4.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64]]);
+    /// 
+    /// assert_eq!(a.elementwise_max(&b).unwrap(), Matrix::from(vec![vec![3.0f64, 4.0f64]]));
+    /// ```
+    pub fn elementwise_max(&self, other: &Matrix<T>) -> Result<Self, MatxError> {
+        self.zip_map(other, |x, y| if y > x { y.clone() } else { x.clone() })
+    }
+
+    /// Method that returns a new matrix holding, for each cell, the smallest value between `self` and `other`, which must have the same shape. On ties or incomparable values (`NaN`), the cell of `self` is kept.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, 4.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![3.0f64, 2.0f64]]);
+    /// 
+    /// assert_eq!(a.elementwise_min(&b).unwrap(), Matrix::from(vec![vec![1.0f64, 2.0f64]]));
+    /// ```
+    pub fn elementwise_min(&self, other: &Matrix<T>) -> Result<Self, MatxError> {
+        self.zip_map(other, |x, y| if y < x { y.clone() } else { x.clone() })
+    }
+
+    /// Method that downsamples the matrix by max-pooling: each cell of the output is the biggest value of a `window`*`window` block, blocks starting every `stride` rows and columns.
+    /// Blocks that would go past the last row or column are dropped, so the output has `(rows - window) / stride + 1` rows (and likewise for columns). A `window` or `stride` of 0 is treated as 1.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64, 0.5f64], 
+    ///     vec![1.2f64, 0.2f64, 9.0f64]
+    /// ]);
+    /// 
+    /// // The last column does not fill a whole window
+    /// assert_eq!(mat.max_pool(2, 2), Matrix::from(vec![vec![3.6f64]]));
+    /// ```
+    pub fn max_pool(&self, window: usize, stride: usize) -> Self {
+        self.pool(window, stride, |cells| {
+            cells.reduce(|best, x| if x > best { x } else { best }).unwrap().clone()
+        })
+    }
+
+    // Index of the first cell in `cells` that no other beats according to `better`
+    fn arg_best<F>(cells: &[T], better: F) -> Option<usize>
+    where F: Fn(&T, &T) -> bool
+    {
+        (0..cells.len()).reduce(|best, i| if better(&cells[i], &cells[best]) { i } else { best })
+    }
+
+    /// Method that returns the (row, column) coordinates of the biggest cell, the first one in row-major order on ties. Returns `None` for an empty matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.argmax(), Some((0, 1)));
+    /// ```
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        Self::arg_best(&self.data, |a, b| a > b).map(|i| (i / self.cols, i % self.cols))
+    }
+
+    /// Method that returns the (row, column) coordinates of the smallest cell, the first one in row-major order on ties. Returns `None` for an empty matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.argmin(), Some((1, 1)));
+    /// ```
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        Self::arg_best(&self.data, |a, b| a < b).map(|i| (i / self.cols, i % self.cols))
+    }
+
+    /// Method that returns, for each row, the column index of its biggest cell (the first one on ties). A matrix without columns gives an empty vector.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let probs = Matrix::<f64>::from(vec![
+    ///     vec![0.1f64, 0.7f64, 0.2f64], 
+    ///     vec![0.5f64, 0.2f64, 0.3f64]
+    /// ]);
+    /// 
+    /// assert_eq!(probs.argmax_rows(), vec![1, 0]);
+    /// ```
+    pub fn argmax_rows(&self) -> Vec<usize> {
+        (0..self.rows).filter_map(|i| Self::arg_best(&self.data[i*self.cols..(i+1)*self.cols], |a, b| a > b)).collect()
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+
+    /// Constructor of a new matrix of size rows*cols where every cell holds `value`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::filled(2, 2, 7.5f64);
+    /// // Gives: 
+    /// // 7.5f64, 7.5f64
+    /// // 7.5f64, 7.5f64
+    /// ```
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            data: vec![value; rows*cols],
+            rows,
+            cols
+        }
+    }
+
+    /// Constructor of a new matrix of size rows*cols out of a flat slice, read row after row: its length must be rows*cols.
+    /// Otherwise, the error holds the expected shape and `(1, len)`, `len` being the length of the slice.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::from_slice(2, 2, &[2.0f64, 3.6f64, 1.2f64, 0.2f64]).unwrap();
+    /// // Gives: 
+    /// // 2.0f64, 3.6f64
+    /// // 1.2f64, 0.2f64
+    /// 
+    /// assert_eq!(Matrix::from_slice(2, 3, &[1.0f64; 4]), Err(MatxError::SizeError((2, 3), (1, 4))));
+    /// ```
+    pub fn from_slice(rows: usize, cols: usize, data: &[T]) -> Result<Self, MatxError> {
+        if data.len() != rows*cols {
+            Err(MatxError::SizeError((rows, cols), (1, data.len())))
+        }
+        else {
+            Ok(Self {
+                data: data.to_vec(),
+                rows,
+                cols
+            })
+        }
+    }
+
+    /// Builds a matrix picking each cell from `if_true` where `mask` is `true`, and from `if_false` elsewhere. All three matrices must have the same dimensions.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![1.0f64, -2.0f64, 3.0f64]]);
+    /// 
+    /// // Replacing negative cells by zeros
+    /// let mask = a.ge(&Matrix::new(1, 3)).unwrap();
+    /// let relu = Matrix::select(&mask, &a, &Matrix::new(1, 3)).unwrap();
+    /// 
+    /// assert_eq!(relu, Matrix::from(vec![vec![1.0f64, 0.0f64, 3.0f64]]));
+    /// ```
+    pub fn select(mask: &Matrix<bool>, if_true: &Matrix<T>, if_false: &Matrix<T>) -> Result<Self, MatxError> {
+
+        for m in [if_true, if_false] {
+            if !m.same_shape(mask) {
+                return Err(MatxError::SizeError((mask.rows, mask.cols), (m.rows, m.cols)));
+            }
+        }
+
+        Ok(Self {
+            data: mask.data.iter().enumerate().map(|(i, m)| if *m { if_true.data[i].clone() } else { if_false.data[i].clone() }).collect(),
+            rows: mask.rows,
+            cols: mask.cols
+        })
+    }
+
+    /// Method that overwrites every cell of the matrix with `value`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::new(2, 2);
+    /// mat.fill(3.0f64);
+    /// 
+    /// assert_eq!(mat, Matrix::filled(2, 2, 3.0f64));
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    pub fn apply<F>(&self, f: F) -> Self 
+    where F: FnMut(&T,) -> T
+    {
+        Self {
+            data: self.data.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    /// Method that returns a `Rows` object, an iterator that iterates over rows of a matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// for r in mat.rows() {
+    ///     println!("{:?}", r);
+    /// }
+    /// ```
+    pub fn rows<'a>(&'a self) -> Rows<'a, T> {
+        Rows::<'a>(self, 0, 0)
+    }
+
+    /// Method that returns a `Columns` object, an iterator that iterates over columns of a matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// for r in mat.cols() {
+    ///     println!("{:?}", r);
+    /// }
+    /// ```
+    pub fn cols<'a>(&'a self) -> Columns<'a, T> {
+        Columns::<'a>(self, 0, 0)
+    }
+
+    /// Method that returns a `Cells` object, an iterator that iterates over cells of a matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// for r in mat.iter() {
+    ///     println!("{:?}", r);
+    /// }
+    /// ```
+    pub fn iter<'a>(&'a self) -> Cells<'a, T> {
+        Cells::<'a>(self.data.iter())
+    }
+
+    /// Method to get the [row ; column] item of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Building the matrix
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let cell: f64 = mat.get(0, 1).unwrap();
+    /// assert_eq!(cell, 3.6f64);
+    /// ```
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        let index = row*self.cols + column;
+
+        if index >= self.data.len() {
+            None
+        }
+        else {
+            Some(self.data[index].clone())
+        }
+    }
+
+    /// Method to get a copy of the `i`th row of the matrix, or `None` if it is out of range.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.row(1).unwrap(), vec![1.2f64, 0.2f64]);
+    /// assert_eq!(mat.row(2), None);
+    /// ```
+    pub fn row(&self, i: usize) -> Option<Vec<T>> {
+        if i >= self.rows {
+            None
+        }
+        else {
+            Some(self.data[i*self.cols..(i+1)*self.cols].to_vec())
+        }
+    }
+
+    /// Method to get a copy of the `j`th column of the matrix, or `None` if it is out of range.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.column(1).unwrap(), vec![3.6f64, 0.2f64]);
+    /// assert_eq!(mat.column(2), None);
+    /// ```
+    pub fn column(&self, j: usize) -> Option<Vec<T>> {
+        if j >= self.cols {
+            None
+        }
+        else {
+            Some(self.data.iter().skip(j).step_by(self.cols).cloned().collect())
+        }
+    }
+
+    /// Method that copies the matrix into a vector of rows, the reverse of `Matrix::from<Vec<Vec<T>>>()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let nested = vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ];
+    /// 
+    /// let mat = Matrix::<f64>::from(nested.clone());
+    /// 
+    /// assert_eq!(mat.to_nested(), nested);
+    /// ```
+    pub fn to_nested(&self) -> Vec<Vec<T>> {
+        (0..self.rows).map(|r| self.data[r*self.cols..(r+1)*self.cols].to_vec()).collect()
+    }
+
+    /// Method that reduces each row to a single value by calling `f` on the row's cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// // Sum of each row
+    /// assert_eq!(mat.reduce_rows(|r| r.iter().sum()), vec![5.6f64, 1.4f64]);
+    /// ```
+    pub fn reduce_rows<F>(&self, f: F) -> Vec<T>
+    where F: Fn(&[T]) -> T
+    {
+        (0..self.rows).map(|i| f(&self.data[i*self.cols..(i+1)*self.cols])).collect()
+    }
+
+    /// Method that reduces each column to a single value by calling `f` on the column's cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// // Maximum of each column
+    /// assert_eq!(mat.reduce_cols(|c| c.iter().cloned().fold(f64::MIN, f64::max)), vec![2.0f64, 3.6f64]);
+    /// ```
+    pub fn reduce_cols<F>(&self, f: F) -> Vec<T>
+    where F: Fn(&[T]) -> T
+    {
+        self.cols().map(|c| f(&c)).collect()
+    }
+
+    /// Method to get a copy of the main diagonal of the matrix, i.e. the cells where `row == column`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.diagonal(), vec![2.0f64, 0.2f64]);
+    /// ```
+    pub fn diagonal(&self) -> Vec<T> {
+        self.diagonal_iter().cloned().collect()
+    }
+
+    /// Method that overwrites the main diagonal of the matrix with `values`, which must hold as many values as the diagonal has cells (the smallest dimension of the matrix).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::new(2, 3);
+    /// mat.set_diagonal(&[1.0f64, 2.0f64]).unwrap();
+    /// 
+    /// assert_eq!(mat.diagonal(), vec![1.0f64, 2.0f64]);
+    /// assert_eq!(mat.set_diagonal(&[1.0f64; 3]), Err(MatxError::SizeError((1, 2), (1, 3))));
+    /// ```
+    pub fn set_diagonal(&mut self, values: &[T]) -> Result<(), MatxError> {
+
+        let n = self.rows.min(self.cols);
+
+        if values.len() != n {
+            return Err(MatxError::SizeError((1, n), (1, values.len())));
+        }
+
+        for (i, value) in values.iter().enumerate() {
+            self.data[i*self.cols+i] = value.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Method to get a copy of the anti-diagonal of the matrix, i.e. the cells where `row + column == cols - 1`, from the top-right corner down.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.anti_diagonal(), vec![3.6f64, 1.2f64]);
+    /// ```
+    pub fn anti_diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols)).map(|i| self.data[i*self.cols + self.cols-1-i].clone()).collect()
+    }
+
+    /// Method to set the [row ; column] item of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Building the matrix
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.set(0.0f64, 0, 1);
+    /// 
+    /// assert_eq!(mat.get(0, 1).unwrap(), 0.0f64);
+    /// ```
+    pub fn set(&mut self, value: T, row: usize, column: usize) -> Result<(), &'static str> {
+        let index = row*self.cols + column;
+
+        if index >= self.data.len() {
+            Err("Index out of range")
+        }
+        else {
+            self.data[index] = value;
+            Ok(())
+        }
+    }
+
+    /// Method that copies `block` into the matrix, the upper-left cell of `block` landing on [`top` ; `left`]. The block must fit entirely inside the matrix; the error holds the shape of the matrix and the (bottom, right) extent the block would need, saturated at `usize::MAX`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::new(3, 3);
+    /// 
+    /// mat.set_submatrix(1, 1, &Matrix::ones(2, 2)).unwrap();
+    /// 
+    /// assert_eq!(mat.row(2).unwrap(), vec![0.0f64, 1.0f64, 1.0f64]);
+    /// assert_eq!(mat.set_submatrix(2, 0, &Matrix::ones(2, 2)), Err(MatxError::SizeError((3, 3), (4, 2))));
+    /// ```
+    pub fn set_submatrix(&mut self, top: usize, left: usize, block: &Matrix<T>) -> Result<(), MatxError> {
+
+        let (bottom, right) = match (top.checked_add(block.rows), left.checked_add(block.cols)) {
+            (Some(bottom), Some(right)) if bottom <= self.rows && right <= self.cols => (bottom, right),
+            (bottom, right) => {
+                return Err(MatxError::SizeError((self.rows, self.cols), (bottom.unwrap_or(usize::MAX), right.unwrap_or(usize::MAX))));
+            }
+        };
+
+        for (i, row) in (top..bottom).enumerate() {
+            self.data[row*self.cols+left..row*self.cols+right].clone_from_slice(&block.data[i*block.cols..(i+1)*block.cols]);
+        }
+
+        Ok(())
+    }
+
+    /// Method that changes the dimensions of the matrix in place. Cells that still fit keep their [row ; column] position, the others are dropped, and new cells are set to `fill`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// mat.resize(1, 3, 0.0f64);
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![2.0f64, 3.6f64, 0.0f64]]));
+    /// ```
+    pub fn resize(&mut self, rows: usize, cols: usize, fill: T) {
+
+        let mut data = Vec::<T>::with_capacity(rows*cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                if i < self.rows && j < self.cols {
+                    data.push(self.data[i*self.cols+j].clone());
+                }
+                else {
+                    data.push(fill.clone());
+                }
+            }
+        }
+
+        self.data = data;
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    /// Method that returns a new matrix surrounded by borders of `value` cells, `top`, `bottom`, `left` and `right` giving the width of each border.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![2.0f64, 3.6f64]]);
+    /// 
+    /// let padded = mat.pad(0, 1, 1, 0, 0.0f64);
+    /// 
+    /// assert_eq!(padded, Matrix::from(vec![vec![0.0f64, 2.0f64, 3.6f64], vec![0.0f64, 0.0f64, 0.0f64]]));
+    /// ```
+    pub fn pad(&self, top: usize, bottom: usize, left: usize, right: usize, value: T) -> Self {
+
+        let rows = top + self.rows + bottom;
+        let cols = left + self.cols + right;
+        let mut data = Vec::<T>::with_capacity(rows*cols);
+
+        data.resize(top*cols, value.clone());
+
+        for i in 0..self.rows {
+            data.resize(data.len() + left, value.clone());
+            data.extend_from_slice(&self.data[i*self.cols..(i+1)*self.cols]);
+            data.resize(data.len() + right, value.clone());
+        }
+
+        data.resize(rows*cols, value);
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+
+    /// Method that returns a new matrix made of `vertical`*`horizontal` copies of the matrix laid out in a grid, giving a matrix of size (rows*`vertical`)*(cols*`horizontal`).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![1.0f64], vec![2.0f64]]);
+    /// 
+    /// assert_eq!(mat.tile(1, 2), Matrix::from(vec![vec![1.0f64, 1.0f64], vec![2.0f64, 2.0f64]]));
+    /// ```
+    pub fn tile(&self, vertical: usize, horizontal: usize) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(self.data.len() * vertical * horizontal);
+
+        for _ in 0..vertical {
+            for i in 0..self.rows {
+                for _ in 0..horizontal {
+                    data.extend_from_slice(&self.data[i*self.cols..(i+1)*self.cols]);
+                }
+            }
+        }
+
+        Self {
+            data,
+            rows: self.rows * vertical,
+            cols: self.cols * horizontal
+        }
+    }
+
+    /// Method that returns the minor of the matrix at (`skip_row`, `skip_col`): a copy of the matrix with that row and that column removed.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.minor(0, 1).unwrap(), Matrix::from(vec![vec![1.2f64]]));
+    /// assert_eq!(mat.minor(2, 1), Err(MatxError::IndexError(2, 2)));
+    /// ```
+    pub fn minor(&self, skip_row: usize, skip_col: usize) -> Result<Self, MatxError> {
+
+        if skip_row >= self.rows {
+            return Err(MatxError::IndexError(skip_row, self.rows));
+        }
+
+        if skip_col >= self.cols {
+            return Err(MatxError::IndexError(skip_col, self.cols));
+        }
+
+        let data = self.enumerate()
+            .filter(|((i, j), _)| *i != skip_row && *j != skip_col)
+            .map(|(_, x)| x.clone())
+            .collect();
+
+        Ok(Self {
+            data,
+            rows: self.rows - 1,
+            cols: self.cols - 1
+        })
+    }
+
+    /// Method that splits a matrix with an even number of rows and columns into four blocks of equal size, returned in the order top-left, top-right, bottom-left, bottom-right.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64], 
+    ///     vec![3.0f64, 4.0f64]
+    /// ]);
+    /// 
+    /// let (a, b, c, d) = mat.split_quadrants().unwrap();
+    /// 
+    /// assert_eq!(a, Matrix::from(vec![vec![1.0f64]]));
+    /// assert_eq!(d, Matrix::from(vec![vec![4.0f64]]));
+    /// ```
+    // `usize::is_multiple_of()` needs Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn split_quadrants(&self) -> Result<(Self, Self, Self, Self), MatxError> {
+
+        if self.rows % 2 != 0 || self.cols % 2 != 0 {
+            return Err(MatxError::SizeError((self.rows + self.rows % 2, self.cols + self.cols % 2), (self.rows, self.cols)));
+        }
+
+        let (rows, cols) = (self.rows / 2, self.cols / 2);
+
+        let block = |top: usize, left: usize| {
+
+            let mut data = Vec::<T>::with_capacity(rows*cols);
+
+            for i in top..top+rows {
+                data.extend_from_slice(&self.data[i*self.cols+left..i*self.cols+left+cols]);
+            }
+
+            Self {
+                data,
+                rows,
+                cols
+            }
+        };
+
+        Ok((block(0, 0), block(0, cols), block(rows, 0), block(rows, cols)))
+    }
+
+    /// Method that returns a new, inverted matrix (same dimensions).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// // Reversing the matrix and stocking the result
+    /// let rev = mat.reverse();
+    /// 
+    /// // What we're supposed to get
+    /// let rev_ = Matrix::<f64>::from(vec![
+    ///     vec![0.2f64, 1.2f64], 
+    ///     vec![3.6f64, 2.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(rev, rev_);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        Self {
+            data: self.data.clone().into_iter().rev().collect(),
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    /// Method that returns a new matrix, rotated by 90 degrees clockwise (dimensions are swapped).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64, 3.0f64], 
+    ///     vec![4.0f64, 5.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// let rot = Matrix::<f64>::from(vec![
+    ///     vec![4.0f64, 1.0f64], 
+    ///     vec![5.0f64, 2.0f64], 
+    ///     vec![6.0f64, 3.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.rotate90(), rot);
+    /// ```
+    pub fn rotate90(&self) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(self.data.len());
+
+        for j in 0..self.cols {
+            for i in (0..self.rows).rev() {
+                data.push(self.data[i*self.cols+j].clone());
+            }
+        }
+
+        Self {
+            data,
+            rows: self.cols,
+            cols: self.rows
+        }
+    }
+
+    /// Method that returns a new matrix, rotated by 180 degrees (same dimensions). This is the same as `.reverse()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64, 3.0f64], 
+    ///     vec![4.0f64, 5.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.rotate180(), mat.rotate90().rotate90());
+    /// ```
+    pub fn rotate180(&self) -> Self {
+        self.reverse()
+    }
+
+    /// Method that returns the transpose of the matrix: rows become columns (dimensions are swapped).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64, 3.0f64], 
+    ///     vec![4.0f64, 5.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// let t = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 4.0f64], 
+    ///     vec![2.0f64, 5.0f64], 
+    ///     vec![3.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.transpose(), t);
+    /// ```
+    pub fn transpose(&self) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(self.data.len());
+
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.data[i*self.cols+j].clone());
+            }
+        }
+
+        Self {
+            data,
+            rows: self.cols,
+            cols: self.rows
+        }
+    }
+
+    /// Method that returns a new matrix mirrored horizontally: each row is reversed.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64, 3.0f64], 
+    ///     vec![4.0f64, 5.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.flip_horizontal().row(0).unwrap(), vec![3.0f64, 2.0f64, 1.0f64]);
+    /// ```
+    pub fn flip_horizontal(&self) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(self.data.len());
+
+        for i in 0..self.rows {
+            data.extend(self.data[i*self.cols..(i+1)*self.cols].iter().rev().cloned());
+        }
+
+        Self {
+            data,
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+    /// Method that returns a new matrix mirrored vertically: the order of the rows is reversed.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64, 3.0f64], 
+    ///     vec![4.0f64, 5.0f64, 6.0f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.flip_vertical().row(0).unwrap(), vec![4.0f64, 5.0f64, 6.0f64]);
+    /// ```
+    pub fn flip_vertical(&self) -> Self {
+
+        let mut data = Vec::<T>::with_capacity(self.data.len());
+
+        for i in (0..self.rows).rev() {
+            data.extend_from_slice(&self.data[i*self.cols..(i+1)*self.cols]);
+        }
+
+        Self {
+            data,
+            rows: self.rows,
+            cols: self.cols
+        }
+    }
+
+}
+
+
+impl <T: rand::distributions::uniform::SampleUniform> Matrix<T> {
+
+    /// Sets all cells of the matrix to a random value in a certain range R.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Building the randomized matrix
+    /// let mat = Matrix::<f64>::rand(5, 5, 0.0f64..10.0f64);
+    /// 
+    /// // Printing the matrix
+    /// println!("{}", mat);
+    /// ```
+    pub fn rand<R: rand::distributions::uniform::SampleRange<T> + Clone>(rows: usize, cols: usize, range: R) -> Self {
+        Self::rand_with(&mut rand::thread_rng(), rows, cols, range)
+    }
+
+    /// Sets all cells of the matrix to a random value in a certain range R, like `Matrix::rand()`, but drawing from a generator seeded with `seed`: the same seed always gives the same matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::rand_seeded(5, 5, 0.0f64..10.0f64, 42);
+    /// let b = Matrix::<f64>::rand_seeded(5, 5, 0.0f64..10.0f64, 42);
+    /// 
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn rand_seeded<R: rand::distributions::uniform::SampleRange<T> + Clone>(rows: usize, cols: usize, range: R, seed: u64) -> Self {
+        Self::rand_with(&mut <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(seed), rows, cols, range)
+    }
+
+    /// Sets all cells of the matrix to a random value in a certain range S, like `Matrix::rand()`, but drawing from the provided generator `rng` (seeded, mocked or shared with the rest of the program).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// use rand::SeedableRng;
+    /// 
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// 
+    /// // Both matrices are drawn from the same generator, one after the other
+    /// let a = Matrix::<f64>::rand_with(&mut rng, 5, 5, 0.0f64..10.0f64);
+    /// let b = Matrix::<f64>::rand_with(&mut rng, 5, 5, 0.0f64..10.0f64);
+    /// 
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn rand_with<R: Rng + ?Sized, S: rand::distributions::uniform::SampleRange<T> + Clone>(rng: &mut R, rows: usize, cols: usize, range: S) -> Self {
+        let mut data = Vec::<T>::new();
+
+        for _i in 0..rows*cols {
+            data.push(rng.gen_range(range.clone()));
+        }
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+}
+
+
+/// Result of `Matrix::lu()`: the lower and upper triangular matrices, and the row permutation.
+pub type LuDecomposition = (Matrix<f64>, Matrix<f64>, Vec<usize>);
+
+impl Matrix<f64> {
+
+    /// Method that compares two matrices of floats: they are deemed equal if they have the same dimensions and no pair of cells differs by more than `epsilon`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<f64>::from(vec![vec![0.1f64 + 0.2f64, 1.0f64]]);
+    /// let b = Matrix::<f64>::from(vec![vec![0.3f64, 1.0f64]]);
+    /// 
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<f64>, epsilon: f64) -> bool {
+        self.same_shape(other)
+            && zip(self.data.iter(), other.data.iter()).all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Method that computes the LU decomposition of a square matrix with partial pivoting: it returns `(L, U, P)` where `L` is lower triangular with a unit diagonal, `U` is upper triangular and `P` is the row permutation so that row `i` of `L * U` is row `P[i]` of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64], 
+    ///     vec![3.0f64, 4.0f64]
+    /// ]);
+    /// 
+    /// let (l, u, p) = mat.lu().unwrap();
+    /// 
+    /// // The biggest pivot, 3, has been moved up
+    /// assert_eq!(p, vec![1, 0]);
+    /// assert!((l * u).unwrap().approx_eq(&Matrix::from(vec![vec![3.0f64, 4.0f64], vec![1.0f64, 2.0f64]]), 1e-12));
+    /// ```
+    pub fn lu(&self) -> Result<LuDecomposition, MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        let n = self.rows;
+        let mut l = Matrix::<f64>::new(n, n);
+        let mut u = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+
+            // Partial pivoting: bringing up the row with the biggest value in column k
+            let pivot = (k..n).fold(k, |p, i| if u.data[i*n+k].abs() > u.data[p*n+k].abs() { i } else { p });
+
+            if pivot != k {
+                u.swap_rows(k, pivot)?;
+                perm.swap(k, pivot);
+
+                for j in 0..k {
+                    l.data.swap(k*n+j, pivot*n+j);
+                }
+            }
+
+            l.data[k*n+k] = 1.0f64;
+
+            if u.data[k*n+k] == 0.0f64 {
+                continue;
+            }
+
+            for i in k+1..n {
+                let factor = u.data[i*n+k] / u.data[k*n+k];
+                l.data[i*n+k] = factor;
+
+                for j in k..n {
+                    u.data[i*n+j] -= factor * u.data[k*n+j];
+                }
+            }
+        }
+
+        Ok((l, u, perm))
+    }
+
+    /// Method that computes the determinant of a square matrix, from its LU decomposition.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64], 
+    ///     vec![3.0f64, 4.0f64]
+    /// ]);
+    /// 
+    /// assert!((mat.determinant().unwrap() + 2.0f64).abs() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> Result<f64, MatxError> {
+
+        let (_, u, mut perm) = self.lu()?;
+        let n = self.rows;
+
+        // Each row swap flips the sign of the determinant
+        let mut sign = 1.0f64;
+
+        for i in 0..n {
+            while perm[i] != i {
+                let j = perm[i];
+                perm.swap(i, j);
+                sign = -sign;
+            }
+        }
+
+        Ok((0..n).fold(sign, |acc, k| acc * u.data[k*n+k]))
+    }
+
+    /// Method that computes the adjugate of a square matrix, i.e. the transpose of its cofactor matrix. It satisfies `self * adj = det * I`, so that for an invertible matrix the inverse is `adj / det`.
+    /// Every cofactor requires a determinant: this is meant for small matrices.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![1.0f64, 2.0f64], 
+    ///     vec![3.0f64, 4.0f64]
+    /// ]);
+    /// 
+    /// let adj = mat.adjugate().unwrap();
+    /// 
+    /// assert!(adj.approx_eq(&Matrix::from(vec![vec![4.0f64, -2.0f64], vec![-3.0f64, 1.0f64]]), 1e-12));
+    /// ```
+    pub fn adjugate(&self) -> Result<Matrix<f64>, MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        let n = self.rows;
+        let mut adj = Matrix::<f64>::new(n, n);
+
+        for i in 0..n {
+            for j in 0..n {
+                let sign = if (i + j) % 2 == 0 { 1.0f64 } else { -1.0f64 };
+                adj.data[i*n+j] = sign * self.minor(j, i)?.determinant()?;
+            }
+        }
+
+        Ok(adj)
+    }
+
+    /// Method that computes the (thin) QR decomposition of a matrix with at least as many rows as columns, using the modified Gram-Schmidt process: it returns `(Q, R)` where `Q` has orthonormal columns, `R` is square and upper triangular, and `self = Q * R`.
+    /// Columns of the matrix must be linearly independent.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![3.0f64, 1.0f64], 
+    ///     vec![4.0f64, 2.0f64]
+    /// ]);
+    /// 
+    /// let (q, r) = mat.qr().unwrap();
+    /// 
+    /// assert!((q * r).unwrap().approx_eq(&mat, 1e-12));
+    /// ```
+    pub fn qr(&self) -> Result<(Matrix<f64>, Matrix<f64>), MatxError> {
+
+        let (m, n) = (self.rows, self.cols);
+
+        if m < n {
+            return Err(MatxError::SizeError((n, n), (m, n)));
+        }
+
+        // Working on columns, the vectors being orthogonalized
+        let mut v: Vec<Vec<f64>> = (0..n).map(|j| (0..m).map(|i| self.data[i*n+j]).collect()).collect();
+        let mut q = Matrix::<f64>::new(m, n);
+        let mut r = Matrix::<f64>::new(n, n);
+
+        let scale = self.data.iter().fold(0.0f64, |s, x| s.max(x.abs()));
+
+        for k in 0..n {
+
+            let norm = v[k].iter().map(|x| x * x).sum::<f64>().sqrt();
+
+            if norm <= f64::EPSILON * m as f64 * scale {
+                return Err(MatxError::SingularError);
+            }
+
+            r.data[k*n+k] = norm;
+            let qk: Vec<f64> = v[k].iter().map(|x| x / norm).collect();
+
+            // Removing the new direction from the remaining vectors right away
+            for (j, vj) in v.iter_mut().enumerate().skip(k+1) {
+                let dot = zip(qk.iter(), vj.iter()).map(|(a, b)| a * b).sum::<f64>();
+                r.data[k*n+j] = dot;
+
+                for (x, q) in vj.iter_mut().zip(qk.iter()) {
+                    *x -= dot * q;
+                }
+            }
+
+            for (i, x) in qk.into_iter().enumerate() {
+                q.data[i*n+k] = x;
+            }
+        }
+
+        Ok((q, r))
+    }
+
+    /// Method that finds the least-squares solution `x` minimizing `||self * x - b||`, for a matrix with at least as many rows as columns (an overdetermined system), using the QR decomposition. Like with `.solve()`, `b` may hold several right-hand sides, one per column.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Fitting y = a*x + b through (0, 1), (1, 3), (2, 5)
+    /// let a = Matrix::<f64>::from(vec![
+    ///     vec![0.0f64, 1.0f64], 
+    ///     vec![1.0f64, 1.0f64], 
+    ///     vec![2.0f64, 1.0f64]
+    /// ]);
+    /// let y = Matrix::<f64>::from(vec![vec![1.0f64], vec![3.0f64], vec![5.0f64]]);
+    /// 
+    /// let coefs = a.lstsq(&y).unwrap();
+    /// 
+    /// assert!(coefs.approx_eq(&Matrix::from(vec![vec![2.0f64], vec![1.0f64]]), 1e-12));
+    /// ```
+    pub fn lstsq(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, MatxError> {
+
+        if b.rows != self.rows {
+            return Err(MatxError::SizeError((self.rows, b.cols), (b.rows, b.cols)));
+        }
+
+        let (q, r) = self.qr()?;
+
+        // R * x = Q^T * b
+        r.solve_triangular(&(q.transpose() * b.clone())?, true)
+    }
+
+    /// Method that computes the Cholesky decomposition of a symmetric positive-definite matrix: it returns the lower triangular matrix `L` such that `self = L * Lᵀ`.
+    /// Only the lower triangle of the matrix is read, symmetry being assumed.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![4.0f64, 2.0f64], 
+    ///     vec![2.0f64, 5.0f64]
+    /// ]);
+    /// 
+    /// let l = mat.cholesky().unwrap();
+    /// 
+    /// assert_eq!(l, Matrix::from(vec![vec![2.0f64, 0.0f64], vec![1.0f64, 2.0f64]]));
+    /// ```
+    pub fn cholesky(&self) -> Result<Matrix<f64>, MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        let n = self.rows;
+        let mut l = Matrix::<f64>::new(n, n);
+
+        for j in 0..n {
+
+            let pivot = self.data[j*n+j] - (0..j).map(|k| l.data[j*n+k].powi(2)).sum::<f64>();
+
+            if pivot <= 0.0f64 || pivot.is_nan() {
+                return Err(MatxError::DefinitenessError);
+            }
+
+            l.data[j*n+j] = pivot.sqrt();
+
+            for i in j+1..n {
+                let acc = (0..j).map(|k| l.data[i*n+k] * l.data[j*n+k]).sum::<f64>();
+                l.data[i*n+j] = (self.data[i*n+j] - acc) / l.data[j*n+j];
+            }
+        }
+
+        Ok(l)
+    }
+
+    /// Method that estimates the eigenvalue of largest magnitude of a square matrix, and a matching unit eigenvector (as a column), using power iteration.
+    /// Iterations stop once the eigenvalue estimate moves by at most `epsilon`, or after `iterations` steps; the latest estimate is returned either way.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.0f64], 
+    ///     vec![0.0f64, -5.0f64]
+    /// ]);
+    /// 
+    /// let (value, vector) = mat.dominant_eigen(1000, 1e-12).unwrap();
+    /// 
+    /// assert!((value + 5.0f64).abs() < 1e-9);
+    /// assert!(vector.get(1, 0).unwrap().abs() > 0.999f64);
+    /// ```
+    pub fn dominant_eigen(&self, iterations: usize, epsilon: f64) -> Result<(f64, Matrix<f64>), MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        let n = self.rows;
+        let mut v = vec![1.0f64 / (n as f64).sqrt(); n];
+        let mut value: Option<f64> = None;
+        let mut restarts = 0usize;
+
+        for _ in 0..iterations {
+
+            let w = (0..n).map(|i| zip(self.data[i*n..(i+1)*n].iter(), v.iter()).map(|(a, b)| a * b).sum::<f64>()).collect::<Vec<f64>>();
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+            if norm == 0.0f64 {
+
+                // v lies in the kernel: start over from the next unit basis vector, unless all of them were tried, the matrix then being zero
+                if restarts == n {
+                    value = Some(0.0f64);
+                    break;
+                }
+
+                v = vec![0.0f64; n];
+                v[restarts] = 1.0f64;
+                restarts += 1;
+                value = None;
+                continue;
+            }
+
+            // Rayleigh quotient, v being a unit vector
+            let next = zip(v.iter(), w.iter()).map(|(a, b)| a * b).sum::<f64>();
+            v = w.into_iter().map(|x| x / norm).collect();
+
+            let converged = matches!(value, Some(prev) if (next - prev).abs() <= epsilon);
+            value = Some(next);
+
+            if converged {
+                break;
+            }
+        }
+
+        Ok((value.unwrap_or(0.0f64), Matrix { data: v, rows: n, cols: 1 }))
+    }
+
+    /// Method that solves the linear system `self * x = b` using the LU decomposition, `self` being a square matrix. `b` may hold several right-hand sides, one per column; `x` then has as many columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // 2x + y = 5
+    /// // x - y = 1
+    /// let a = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 1.0f64], 
+    ///     vec![1.0f64, -1.0f64]
+    /// ]);
+    /// let b = Matrix::<f64>::from(vec![vec![5.0f64], vec![1.0f64]]);
+    /// 
+    /// let x = a.solve(&b).unwrap();
+    /// 
+    /// assert!(x.approx_eq(&Matrix::from(vec![vec![2.0f64], vec![1.0f64]]), 1e-12));
+    /// ```
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, MatxError> {
+
+        let (l, u, perm) = self.lu()?;
+        let n = self.rows;
+
+        if b.rows != n {
+            return Err(MatxError::SizeError((n, b.cols), (b.rows, b.cols)));
+        }
+
+        // Pivots that vanish compared to the magnitude of the matrix mean it is singular
+        let scale = self.data.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+        if (0..n).any(|k| u.data[k*n+k].abs() <= f64::EPSILON * n as f64 * scale) {
+            return Err(MatxError::SingularError);
+        }
+
+        let pb = perm.iter().flat_map(|i| b.data[i*b.cols..(i+1)*b.cols].to_vec()).collect();
+
+        // L * y = P * b, then U * x = y
+        let y = l.solve_triangular(&Matrix::try_new(n, b.cols, pb)?, false)?;
+        u.solve_triangular(&y, true)
+    }
+
+    /// Method that solves the linear system `self * x = b` by substitution, `self` being a square triangular matrix: upper triangular if `upper` is set (back substitution), lower triangular otherwise (forward substitution). Cells on the other side of the diagonal are not read.
+    /// Like with `.solve()`, `b` may hold several right-hand sides, one per column.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // 2x + y = 5
+    /// //      y = 1
+    /// let a = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 1.0f64], 
+    ///     vec![0.0f64, 1.0f64]
+    /// ]);
+    /// let b = Matrix::<f64>::from(vec![vec![5.0f64], vec![1.0f64]]);
+    /// 
+    /// assert_eq!(a.solve_triangular(&b, true).unwrap(), Matrix::from(vec![vec![2.0f64], vec![1.0f64]]));
+    /// ```
+    pub fn solve_triangular(&self, b: &Matrix<f64>, upper: bool) -> Result<Matrix<f64>, MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        let n = self.rows;
+
+        if b.rows != n {
+            return Err(MatxError::SizeError((n, b.cols), (b.rows, b.cols)));
+        }
+
+        if (0..n).any(|i| self.data[i*n+i] == 0.0f64) {
+            return Err(MatxError::SingularError);
+        }
+
+        let mut x = Matrix::<f64>::new(n, b.cols);
+
+        for c in 0..b.cols {
+            for step in 0..n {
+                let i = if upper { n - 1 - step } else { step };
+                let known = if upper { i+1..n } else { 0..i };
+
+                let acc = known.map(|k| self.data[i*n+k] * x.data[k*b.cols+c]).sum::<f64>();
+                x.data[i*b.cols+c] = (b.data[i*b.cols+c] - acc) / self.data[i*n+i];
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Sets all cells of the matrix to a random value drawn from a normal (Gaussian) distribution of mean `mean` and standard deviation `std`.
+    /// `std` must be finite and non-negative (panics otherwise, `NaN` included).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// // Building the randomized matrix
+    /// let mat = Matrix::randn(5, 5, 0.0f64, 1.0f64);
+    /// 
+    /// // Printing the matrix
+    /// println!("{}", mat);
+    /// ```
+    pub fn randn(rows: usize, cols: usize, mean: f64, std: f64) -> Matrix<f64> {
+
+        // `Normal` alone accepts negative deviations, mirroring the distribution
+        assert!(std >= 0.0f64, "the standard deviation must be non-negative");
+        let normal = rand_distr::Normal::new(mean, std).expect("the standard deviation must be finite");
+        let mut rng = rand::thread_rng();
+
+        Self {
+            data: (0..rows*cols).map(|_| rng.sample(normal)).collect(),
+            rows,
+            cols
+        }
+    }
+
+    /// Builds a matrix by sampling `f(x, y)` over a grid: `x` goes evenly from `x_range.0` to `x_range.1` along the columns, and `y` from `y_range.0` to `y_range.1` along the rows (both bounds included).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::sample_grid(3, 5, (0.0, 1.0), (0.0, 2.0), |x, y| x + y);
+    /// 
+    /// assert_eq!(mat.get(0, 0).unwrap(), 0.0f64);
+    /// assert_eq!(mat.get(0, 4).unwrap(), 1.0f64);
+    /// assert_eq!(mat.get(2, 4).unwrap(), 3.0f64);
+    /// ```
+    pub fn sample_grid<F: FnMut(f64, f64) -> f64>(rows: usize, cols: usize, x_range: (f64, f64), y_range: (f64, f64), mut f: F) -> Matrix<f64> {
+
+        // A single sample along an axis sits on the lower bound
+        let step = |range: (f64, f64), n: usize| if n > 1 { (range.1 - range.0) / (n - 1) as f64 } else { 0.0f64 };
+        let (x_step, y_step) = (step(x_range, cols), step(y_range, rows));
+
+        let mut data = Vec::<f64>::with_capacity(rows*cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(x_range.0 + j as f64 * x_step, y_range.0 + i as f64 * y_step));
+            }
+        }
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+}
+
+
+#[cfg(feature = "csv")]
+impl<T: Display> Matrix<T> {
+
+    /// Writes the matrix as CSV into `w`, one line per row, using `delimiter` to separate cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let mut out = Vec::<u8>::new();
+    /// mat.to_csv(&mut out, b',').unwrap();
+    /// 
+    /// assert_eq!(String::from_utf8(out).unwrap(), "2,3.6\n1.2,0.2\n");
+    /// ```
+    pub fn to_csv<W: std::io::Write>(&self, w: W, delimiter: u8) -> std::io::Result<()> {
+
+        let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(w);
+
+        for i in 0..self.rows {
+            writer.write_record(self.data[i*self.cols..(i+1)*self.cols].iter().map(|x| x.to_string()))?;
+        }
+
+        writer.flush()
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Matrix<f64> {
+
+    /// Reads a matrix of floats from CSV data, each line being a row. All lines must have the same number of cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from_csv("2;3.6\n1.2;0.2\n".as_bytes(), b';').unwrap();
+    /// 
+    /// assert_eq!(mat.get(1, 0).unwrap(), 1.2f64);
+    /// ```
+    pub fn from_csv<R: std::io::Read>(r: R, delimiter: u8) -> Result<Matrix<f64>, MatxError> {
+
+        let mut reader = csv::ReaderBuilder::new().delimiter(delimiter).has_headers(false).from_reader(r);
+
+        let mut data = Vec::<f64>::new();
+        let mut rows = 0;
+
+        for record in reader.records() {
+            let record = record.map_err(|e| MatxError::ParseError(e.to_string()))?;
+
+            for cell in record.iter() {
+                data.push(cell.trim().parse::<f64>().map_err(|e| MatxError::ParseError(format!("{}: {:?}", e, cell)))?);
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self {
+            cols: data.len().checked_div(rows).unwrap_or(0),
+            data,
+            rows
+        })
+    }
+}
+
+
+#[cfg(feature = "bincode")]
+impl<T: Serialize + serde::de::DeserializeOwned> Matrix<T> {
+
+    /// Method that serializes the matrix into a compact binary form using `bincode`, to be read back with `Matrix::from_bytes()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let bytes = mat.to_bytes().unwrap();
+    /// 
+    /// assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), mat);
+    /// ```
+    pub fn to_bytes(&self) -> Result<Vec<u8>, MatxError> {
+        bincode::serialize(self).map_err(|e| MatxError::ParseError(e.to_string()))
+    }
+
+    /// Constructor of a new matrix out of bytes produced by `.to_bytes()`. Bytes that cannot be decoded, or that hold a number of cells not matching the dimensions, give a `ParseError`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let bytes = Matrix::<f64>::ones(2, 3).to_bytes().unwrap();
+    /// 
+    /// assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), Matrix::ones(2, 3));
+    /// assert!(Matrix::<f64>::from_bytes(&bytes[..10]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MatxError> {
+
+        let mat: Self = bincode::deserialize(bytes).map_err(|e| MatxError::ParseError(e.to_string()))?;
+
+        if Some(mat.data.len()) != mat.rows.checked_mul(mat.cols) {
+            return Err(MatxError::ParseError(format!("{} cells do not fit a {}*{} matrix", mat.data.len(), mat.rows, mat.cols)));
+        }
+
+        Ok(mat)
+    }
+}
+
+
+#[cfg(feature = "npy")]
+impl Matrix<f64> {
+
+    /// Writes the matrix into `w` in NumPy's `.npy` format (version 1.0, little-endian `f64` cells in C order), so that it can be loaded with `numpy.load()`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let mut out = Vec::<u8>::new();
+    /// mat.to_npy(&mut out).unwrap();
+    /// 
+    /// assert_eq!(Matrix::<f64>::from_npy(out.as_slice()).unwrap(), mat);
+    /// ```
+    pub fn to_npy<W: std::io::Write>(&self, mut w: W) -> std::io::Result<()> {
+
+        let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}", self.rows, self.cols);
+
+        // Magic string, version and header length take 10 bytes; the whole preamble is padded to a multiple of 64 bytes, ending with a newline
+        let total = (10 + header.len() + 1).div_ceil(64) * 64;
+        header.push_str(&" ".repeat(total - 10 - header.len() - 1));
+        header.push('\n');
+
+        w.write_all(b"\x93NUMPY\x01\x00")?;
+        w.write_all(&(header.len() as u16).to_le_bytes())?;
+        w.write_all(header.as_bytes())?;
+
+        for x in self.data.iter() {
+            w.write_all(&x.to_le_bytes())?;
+        }
+
+        w.flush()
+    }
+
+    /// Reads a matrix from NumPy's `.npy` format. The array must hold little-endian `f64` cells and have one (read as a row) or two dimensions; both C and Fortran orders are supported.
+    pub fn from_npy<R: std::io::Read>(mut r: R) -> Result<Matrix<f64>, MatxError> {
+
+        let io_error = |e: std::io::Error| MatxError::ParseError(e.to_string());
+
+        let mut preamble = [0u8; 8];
+        r.read_exact(&mut preamble).map_err(io_error)?;
+
+        if &preamble[..6] != b"\x93NUMPY" {
+            return Err(MatxError::ParseError("not a npy file".to_string()));
+        }
+
+        // Version 1.0 stores the header length on two bytes, later versions on four
+        let header_len = if preamble[6] == 1 {
+            let mut len = [0u8; 2];
+            r.read_exact(&mut len).map_err(io_error)?;
+            u16::from_le_bytes(len) as usize
+        }
+        else {
+            let mut len = [0u8; 4];
+            r.read_exact(&mut len).map_err(io_error)?;
+            u32::from_le_bytes(len) as usize
+        };
+
+        let mut header = Vec::<u8>::new();
+        std::io::Read::read_to_end(&mut std::io::Read::take(&mut r, header_len as u64), &mut header).map_err(io_error)?;
+
+        if header.len() != header_len {
+            return Err(MatxError::ParseError("truncated npy header".to_string()));
+        }
+
+        let header = String::from_utf8_lossy(&header);
+
+        // Value of a key in the header dictionary, up to the next comma (or closing parenthesis for tuples)
+        let field = |key: &str, end: char| {
+            header.split_once(&format!("'{}':", key))
+                .and_then(|(_, rest)| rest.split_once(end).map(|(value, _)| value.trim().to_string()))
+                .ok_or_else(|| MatxError::ParseError(format!("missing {:?} in npy header", key)))
+        };
+
+        let descr = field("descr", ',')?;
+
+        if descr != "'<f8'" {
+            return Err(MatxError::ParseError(format!("unsupported npy dtype: {}", descr)));
+        }
+
+        let fortran_order = field("fortran_order", ',')? == "True";
+
+        let shape = field("shape", ')')?
+            .trim_start_matches('(')
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(|x| x.parse::<usize>().map_err(|e| MatxError::ParseError(format!("{}: {:?}", e, x))))
+            .collect::<Result<Vec<usize>, MatxError>>()?;
+
+        let (rows, cols) = match shape[..] {
+            [n] => (1, n),
+            [rows, cols] => (rows, cols),
+            _ => return Err(MatxError::ParseError(format!("unsupported npy shape: {:?}", shape)))
+        };
+
+        // The header cannot be trusted: the size is checked, and the data is not allocated upfront
+        let len = rows.checked_mul(cols).and_then(|n| n.checked_mul(8))
+            .ok_or_else(|| MatxError::ParseError(format!("npy shape too big: {:?}", shape)))?;
+
+        let mut bytes = Vec::<u8>::new();
+        std::io::Read::read_to_end(&mut r.take(len as u64), &mut bytes).map_err(io_error)?;
+
+        if bytes.len() != len {
+            return Err(MatxError::ParseError(format!("expected {} bytes of npy data, got {}", len, bytes.len())));
+        }
+
+        let data = bytes.chunks_exact(8).map(|x| f64::from_le_bytes(x.try_into().unwrap())).collect();
+
+        if fortran_order {
+            Ok(Matrix::<f64> { data, rows: cols, cols: rows }.transpose())
+        }
+        else {
+            Ok(Matrix::<f64> { data, rows, cols })
+        }
+    }
+}
+
+
+impl<T: std::iter::Sum + Clone>  Matrix<T> {
+
+    /// Method that returns the sum of all cells in the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// let expected = 2.0f64 + 3.6f64 + 1.2f64 + 0.2f64;
+    /// 
+    /// assert_eq!(expected, mat.sum());
+    /// ```
+    pub fn sum(&self) -> T {
+        self.data.clone().into_iter().sum()
+    }
+}
+
+impl<T: num::CheckedAdd> Matrix<T> {
+
+    /// Method that adds two matrices like the `+` operator, but returns an `OverflowError` instead of overflowing the element type.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<u8>::from(vec![vec![100, 200]]);
+    /// 
+    /// assert_eq!(a.checked_add(&Matrix::from(vec![vec![50, 50]])), Ok(Matrix::from(vec![vec![150, 250]])));
+    /// assert_eq!(a.checked_add(&a), Err(MatxError::OverflowError));
+    /// ```
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatxError> {
+
+        if !self.same_shape(rhs) {
+            return Err(MatxError::SizeError((self.rows, self.cols), (rhs.rows, rhs.cols)));
+        }
+
+        Ok(Self {
+            data: zip(self.data.iter(), rhs.data.iter()).map(|(a, b)| a.checked_add(b)).collect::<Option<Vec<T>>>().ok_or(MatxError::OverflowError)?,
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+}
+
+impl<T: num::CheckedAdd + num::CheckedMul + num::Zero> Matrix<T> {
+
+    /// Method that computes the matrix product like the `*` operator, but returns an `OverflowError` instead of overflowing the element type.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<i8>::from(vec![vec![10, 10]]);
+    /// let b = Matrix::<i8>::from(vec![vec![5], vec![6]]);
+    /// 
+    /// assert_eq!(a.checked_mul(&b), Ok(Matrix::from(vec![vec![110]])));
+    /// assert_eq!(a.checked_mul(&(b.clone() + 1)), Err(MatxError::OverflowError));
+    /// ```
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatxError> {
+
+        if self.cols != rhs.rows {
+            return Err(MatxError::DimensionMismatch(self.cols, rhs.rows));
+        }
+
+        let mut data = Vec::<T>::with_capacity(self.rows*rhs.cols);
+
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+
+                let mut acc = T::zero();
+
+                for k in 0..self.cols {
+                    let product = self.data[i*self.cols+k].checked_mul(&rhs.data[k*rhs.cols+j]).ok_or(MatxError::OverflowError)?;
+                    acc = acc.checked_add(&product).ok_or(MatxError::OverflowError)?;
+                }
+
+                data.push(acc);
+            }
+        }
+
+        Ok(Self {
+            data,
+            rows: self.rows,
+            cols: rhs.cols
+        })
+    }
+}
+
+impl<T: std::ops::Div<Output = T> + num::Zero + Clone> Matrix<T> {
+
+    /// Method that divides every cell by `rhs` like the `/` operator, but returns a `DivideByZero` error when `rhs` is zero instead of panicking (integers) or producing infinities (floats).
+    /// Integer cells still use truncating division.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![vec![7, -9]]);
+    /// 
+    /// assert_eq!(mat.div_scalar_checked(2), Ok(Matrix::from(vec![vec![3, -4]])));
+    /// assert_eq!(mat.div_scalar_checked(0), Err(MatxError::DivideByZero));
+    /// ```
+    pub fn div_scalar_checked(&self, rhs: T) -> Result<Matrix<T>, MatxError> {
+
+        if rhs.is_zero() {
+            return Err(MatxError::DivideByZero);
+        }
+
+        Ok(Self {
+            data: self.data.iter().map(|x| x.clone() / rhs.clone()).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+}
+
+impl<T: num::Signed + Clone> Matrix<T> {
+
+    /// Method that returns a new matrix holding the absolute value of each cell.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![vec![-2.0f64, 3.6f64]]);
+    /// 
+    /// assert_eq!(mat.abs(), Matrix::from(vec![vec![2.0f64, 3.6f64]]));
+    /// ```
+    pub fn abs(&self) -> Self {
+        self.apply(|x| x.abs())
+    }
+
+    /// Method that returns a new matrix holding the sign of each cell: 1 for positive cells, -1 for negative ones, and 0 for zeros (integers only; floats follow `f64::signum()`).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![vec![-2, 0, 5]]);
+    /// 
+    /// assert_eq!(mat.signum(), Matrix::from(vec![vec![-1, 0, 1]]));
+    /// ```
+    pub fn signum(&self) -> Self {
+        self.apply(|x| x.signum())
+    }
+}
+
+impl<T: num::Zero + Clone> Matrix<T> {
+
+    /// Method that counts the nonzero cells of the matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.0f64], 
+    ///     vec![0.0f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.nnz(), 2);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        self.data.iter().filter(|x| !x.is_zero()).count()
+    }
+
+    /// Method that tells whether the matrix is diagonal: all cells off the main diagonal are zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// assert!(Matrix::<i32>::from(vec![vec![1, 0], vec![0, 2]]).is_diagonal());
+    /// assert!(!Matrix::<i32>::from(vec![vec![1, 0], vec![3, 2]]).is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        self.enumerate().all(|((i, j), x)| i == j || x.is_zero())
+    }
+
+    /// Method that tells whether the matrix is upper triangular: all cells below the main diagonal are zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// assert!(Matrix::<i32>::from(vec![vec![1, 5], vec![0, 2]]).is_upper_triangular());
+    /// assert!(!Matrix::<i32>::from(vec![vec![1, 0], vec![3, 2]]).is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool {
+        self.enumerate().all(|((i, j), x)| i <= j || x.is_zero())
+    }
+
+    /// Method that tells whether the matrix is lower triangular: all cells above the main diagonal are zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// assert!(Matrix::<i32>::from(vec![vec![1, 0], vec![3, 2]]).is_lower_triangular());
+    /// assert!(!Matrix::<i32>::from(vec![vec![1, 5], vec![0, 2]]).is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool {
+        self.enumerate().all(|((i, j), x)| i >= j || x.is_zero())
+    }
+
+    /// Method that lists the nonzero cells of the matrix as (row, column, value) triplets, in row-major order (the coordinate, or COO, sparse format).
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 0.0f64], 
+    ///     vec![0.0f64, 0.2f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.to_triplets(), vec![(0, 0, 2.0f64), (1, 1, 0.2f64)]);
+    /// ```
+    pub fn to_triplets(&self) -> Vec<(usize, usize, T)> {
+        self.enumerate()
+            .filter(|(_, x)| !x.is_zero())
+            .map(|((i, j), x)| (i, j, x.clone()))
+            .collect()
+    }
+}
+
+impl<T: std::ops::Add<Output = T> + Clone> Matrix<T> {
+
+    /// Method that returns the cumulative sums along each row: cell [i ; j] holds the sum of cells [i ; 0] to [i ; j].
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![vec![1, 2, 3]]);
+    /// 
+    /// assert_eq!(mat.cumsum_rows(), Matrix::from(vec![vec![1, 3, 6]]));
+    /// ```
+    pub fn cumsum_rows(&self) -> Matrix<T> {
+
+        let mut out = self.clone();
+
+        for i in 0..self.rows {
+            for j in 1..self.cols {
+                out.data[i*self.cols+j] = out.data[i*self.cols+j-1].clone() + self.data[i*self.cols+j].clone();
+            }
+        }
+
+        out
+    }
+
+    /// Method that returns the cumulative sums along each column: cell [i ; j] holds the sum of cells [0 ; j] to [i ; j].
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![vec![1], vec![2], vec![3]]);
+    /// 
+    /// assert_eq!(mat.cumsum_cols(), Matrix::from(vec![vec![1], vec![3], vec![6]]));
+    /// ```
+    pub fn cumsum_cols(&self) -> Matrix<T> {
+
+        let mut out = self.clone();
+
+        for i in 1..self.rows {
+            for j in 0..self.cols {
+                out.data[i*self.cols+j] = out.data[(i-1)*self.cols+j].clone() + self.data[i*self.cols+j].clone();
+            }
+        }
+
+        out
+    }
+
+    /// Method that adds the row vector `v` to every row of the matrix. `v` must have as many elements as the matrix has columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::new(2, 2);
+    /// 
+    /// let biased = mat.add_row_vector(&[1.0f64, 2.0f64]).unwrap();
+    /// 
+    /// assert_eq!(biased, Matrix::from(vec![vec![1.0f64, 2.0f64], vec![1.0f64, 2.0f64]]));
+    /// ```
+    pub fn add_row_vector(&self, v: &[T]) -> Result<Matrix<T>, MatxError> {
+
+        if v.len() != self.cols {
+            return Err(MatxError::SizeError((1, self.cols), (1, v.len())));
+        }
+
+        Ok(Self {
+            data: self.data.iter().enumerate().map(|(i, x)| x.clone() + v[i % self.cols].clone()).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+
+    /// Method that adds the column vector `v` to every column of the matrix. `v` must have as many elements as the matrix has rows.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::new(2, 2);
+    /// 
+    /// let biased = mat.add_col_vector(&[1.0f64, 2.0f64]).unwrap();
+    /// 
+    /// assert_eq!(biased, Matrix::from(vec![vec![1.0f64, 1.0f64], vec![2.0f64, 2.0f64]]));
+    /// ```
+    pub fn add_col_vector(&self, v: &[T]) -> Result<Matrix<T>, MatxError> {
+
+        if v.len() != self.rows {
+            return Err(MatxError::SizeError((self.rows, 1), (v.len(), 1)));
+        }
+
+        Ok(Self {
+            data: self.data.iter().enumerate().map(|(i, x)| x.clone() + v[i / self.cols].clone()).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + Clone> Matrix<T> {
+
+    /// Method that multiplies every cell of row `row` by `factor`, in place.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::ones(2, 2);
+    /// mat.scale_row(1, 3.0f64).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![1.0f64, 1.0f64], vec![3.0f64, 3.0f64]]));
+    /// assert_eq!(mat.scale_row(2, 3.0f64), Err(MatxError::IndexError(2, 2)));
+    /// ```
+    pub fn scale_row(&mut self, row: usize, factor: T) -> Result<(), MatxError> {
+
+        if row >= self.rows {
+            return Err(MatxError::IndexError(row, self.rows));
+        }
+
+        for x in self.data[row*self.cols..(row+1)*self.cols].iter_mut() {
+            *x = x.clone() * factor.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Method that multiplies every cell of column `col` by `factor`, in place.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::ones(2, 2);
+    /// mat.scale_col(1, 3.0f64).unwrap();
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![1.0f64, 3.0f64], vec![1.0f64, 3.0f64]]));
+    /// assert_eq!(mat.scale_col(2, 3.0f64), Err(MatxError::IndexError(2, 2)));
+    /// ```
+    pub fn scale_col(&mut self, col: usize, factor: T) -> Result<(), MatxError> {
+
+        if col >= self.cols {
+            return Err(MatxError::IndexError(col, self.cols));
+        }
+
+        for x in self.data.iter_mut().skip(col).step_by(self.cols) {
+            *x = x.clone() * factor.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Method that multiplies each row `i` of the matrix by `v[i]`, as a product with a diagonal matrix on the left would. `v` must have as many elements as the matrix has rows.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::ones(2, 2);
+    /// 
+    /// let scaled = mat.scale_rows_by(&[1.0f64, 2.0f64]).unwrap();
+    /// 
+    /// assert_eq!(scaled, Matrix::from(vec![vec![1.0f64, 1.0f64], vec![2.0f64, 2.0f64]]));
+    /// ```
+    pub fn scale_rows_by(&self, v: &[T]) -> Result<Matrix<T>, MatxError> {
+
+        if v.len() != self.rows {
+            return Err(MatxError::SizeError((self.rows, 1), (v.len(), 1)));
+        }
+
+        Ok(Self {
+            data: self.data.iter().enumerate().map(|(i, x)| x.clone() * v[i / self.cols].clone()).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+
+    /// Method that multiplies each column `j` of the matrix by `v[j]`, as a product with a diagonal matrix on the right would. `v` must have as many elements as the matrix has columns.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::ones(2, 2);
+    /// 
+    /// let scaled = mat.scale_cols_by(&[1.0f64, 2.0f64]).unwrap();
+    /// 
+    /// assert_eq!(scaled, Matrix::from(vec![vec![1.0f64, 2.0f64], vec![1.0f64, 2.0f64]]));
+    /// ```
+    pub fn scale_cols_by(&self, v: &[T]) -> Result<Matrix<T>, MatxError> {
+
+        if v.len() != self.cols {
+            return Err(MatxError::SizeError((1, self.cols), (1, v.len())));
+        }
+
+        Ok(Self {
+            data: self.data.iter().enumerate().map(|(i, x)| x.clone() * v[i % self.cols].clone()).collect(),
+            rows: self.rows,
+            cols: self.cols
+        })
+    }
+
+    /// Builds the outer product of two vectors: an `a.len()`*`b.len()` matrix where cell [i ; j] is `a[i] * b[j]`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::outer(&[1, 2], &[3, 4]);
+    /// 
+    /// assert_eq!(mat, Matrix::from(vec![vec![3, 4], vec![6, 8]]));
+    /// ```
+    pub fn outer(a: &[T], b: &[T]) -> Matrix<T> {
+        Self {
+            data: a.iter().flat_map(|x| b.iter().map(move |y| x.clone() * y.clone())).collect(),
+            rows: a.len(),
+            cols: b.len()
+        }
+    }
+
+    /// Method that computes the Kronecker product of two matrices: each cell `a` of `self` is replaced by the block `a * rhs`, hence a (rows*rhs.rows)*(cols*rhs.cols) matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<i32>::from(vec![vec![1, 2]]);
+    /// let b = Matrix::<i32>::from(vec![vec![1], vec![10]]);
+    /// 
+    /// assert_eq!(a.kron(&b), Matrix::from(vec![vec![1, 2], vec![10, 20]]));
+    /// ```
+    pub fn kron(&self, rhs: &Matrix<T>) -> Matrix<T> {
+
+        let rows = self.rows * rhs.rows;
+        let cols = self.cols * rhs.cols;
+        let mut data = Vec::<T>::with_capacity(rows*cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let a = self.data[(i / rhs.rows)*self.cols + j / rhs.cols].clone();
+                let b = rhs.data[(i % rhs.rows)*rhs.cols + j % rhs.cols].clone();
+                data.push(a * b);
+            }
+        }
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + Clone
+{
+
+    /// Method that adds `factor` times row `src` to row `dest`, in place; the elementary step of Gaussian elimination.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mut mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 4.0f64], 
+    ///     vec![1.0f64, 3.0f64]
+    /// ]);
+    /// 
+    /// mat.add_scaled_row(1, 0, -0.5f64).unwrap();
+    /// 
+    /// assert_eq!(mat.row(1).unwrap(), vec![0.0f64, 1.0f64]);
+    /// ```
+    pub fn add_scaled_row(&mut self, dest: usize, src: usize, factor: T) -> Result<(), MatxError> {
+
+        for index in [dest, src] {
+            if index >= self.rows {
+                return Err(MatxError::IndexError(index, self.rows));
+            }
+        }
+
+        for j in 0..self.cols {
+            self.data[dest*self.cols+j] = self.data[dest*self.cols+j].clone() + factor.clone() * self.data[src*self.cols+j].clone();
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T>
+where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + num::NumCast + Clone
+{
+
+    /// Method that computes the 2D convolution of the matrix with `kernel` (the kernel is flipped, as opposed to a correlation). `mode` sets the size of the output; for `ConvMode::Same`, the kernel is centered on each cell (on the upper-left of the center for even dimensions).
+    /// An empty kernel (no row or no column) gives a matrix of zeros with the shape of `self`, whatever the mode.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<i32>::from(vec![
+    ///     vec![1, 2, 3], 
+    ///     vec![4, 5, 6]
+    /// ]);
+    /// 
+    /// // Difference with the cell on the left
+    /// let kernel = Matrix::<i32>::from(vec![vec![1, -1]]);
+    /// 
+    /// assert_eq!(mat.convolve2d(&kernel, ConvMode::Valid), Matrix::from(vec![vec![1, 1], vec![1, 1]]));
+    /// ```
+    pub fn convolve2d(&self, kernel: &Matrix<T>, mode: ConvMode) -> Matrix<T> {
+
+        if kernel.rows == 0 || kernel.cols == 0 {
+            return Matrix::<T>::new(self.rows, self.cols);
+        }
+
+        let kernel = kernel.rotate180();
+        let zero: T = num::NumCast::from(0).unwrap();
+
+        let input = match mode {
+            ConvMode::Valid => self.clone(),
+            ConvMode::Same => {
+                let (top, left) = ((kernel.rows - 1) / 2, (kernel.cols - 1) / 2);
+                let (bottom, right) = (kernel.rows - 1 - top, kernel.cols - 1 - left);
+                self.pad(top, bottom, left, right, zero)
+            }
+        };
+
+        let rows = (input.rows + 1).saturating_sub(kernel.rows);
+        let cols = (input.cols + 1).saturating_sub(kernel.cols);
+        let mut out = Matrix::<T>::new(rows, cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                for ki in 0..kernel.rows {
+                    for kj in 0..kernel.cols {
+                        out.data[i*cols+j] = out.data[i*cols+j].clone() + input.data[(i+ki)*input.cols + j+kj].clone() * kernel.data[ki*kernel.cols+kj].clone();
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Method that returns the trace of a square matrix, the sum of its main diagonal.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.5f64]
+    /// ]);
+    /// 
+    /// assert_eq!(mat.trace().unwrap(), 2.5f64);
+    /// ```
+    pub fn trace(&self) -> Result<T, MatxError> {
+
+        if !self.is_square() {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
+        }
+
+        Ok((0..self.rows).fold(num::NumCast::from(0).unwrap(), |acc: T, i| acc + self.data[i*self.cols+i].clone()))
+    }
+
+    /// Method that computes the dot product of two vectors, each being a matrix with a single row or a single column. Both must have the same number of cells.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<i32>::from(vec![vec![1, 2, 3]]);
+    /// let b = Matrix::<i32>::from(vec![vec![4], vec![5], vec![6]]);
+    /// 
+    /// assert_eq!(a.dot(&b).unwrap(), 32);
+    /// ```
+    pub fn dot(&self, other: &Matrix<T>) -> Result<T, MatxError> {
+
+        let is_vector = |m: &Matrix<T>| m.rows == 1 || m.cols == 1;
+
+        if !is_vector(self) || !is_vector(other) || self.data.len() != other.data.len() {
+            return Err(MatxError::SizeError((self.rows, self.cols), (other.rows, other.cols)));
+        }
+
+        Ok(zip(self.data.iter(), other.data.iter()).fold(num::NumCast::from(0).unwrap(), |acc: T, (a, b)| acc + a.clone() * b.clone()))
+    }
+
+    /// Method that computes the trace of the product `self * other` without computing the whole product: only its diagonal cells are summed up.
+    /// `self.cols` must equal `other.rows`, and `self.rows` must equal `other.cols`.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let a = Matrix::<i32>::from(vec![vec![1, 2, 3]]);
+    /// let b = Matrix::<i32>::from(vec![vec![4], vec![5], vec![6]]);
+    /// 
+    /// assert_eq!(a.trace_of_product(&b).unwrap(), 32);
     /// ```
-    pub fn get(&self, row: usize, column: usize) -> Option<T> {
-        let index = row*self.cols + column;
+    pub fn trace_of_product(&self, other: &Matrix<T>) -> Result<T, MatxError> {
 
-        if index >= self.data.len() {
-            None
+        if self.cols != other.rows || self.rows != other.cols {
+            return Err(MatxError::SizeError((self.rows, self.cols), (other.rows, other.cols)));
         }
-        else {
-            Some(self.data[index].clone())
+
+        let mut acc: T = num::NumCast::from(0).unwrap();
+
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                acc = acc + self.data[i*self.cols+k].clone() * other.data[k*other.cols+i].clone();
+            }
         }
+
+        Ok(acc)
     }
 
-    /// Method to set the [row ; column] item of the matrix.
+    /// Method that raises a square matrix to the power `n` (as in `A * A * ... * A`, `n` times) using repeated squaring. `A^0` is the identity matrix.
     /// 
     /// # Examples
     /// 
@@ -200,29 +4097,51 @@ impl<T: Clone> Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// // Building the matrix
-    /// let mut mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
+    /// let mat = Matrix::<i32>::from(vec![
+    ///     vec![1, 1], 
+    ///     vec![1, 0]
     /// ]);
     /// 
-    /// mat.set(0.0f64, 0, 1);
+    /// let expected = Matrix::<i32>::from(vec![
+    ///     vec![8, 5], 
+    ///     vec![5, 3]
+    /// ]);
     /// 
-    /// assert_eq!(mat.get(0, 1).unwrap(), 0.0f64);
+    /// assert_eq!(mat.powi(5).unwrap(), expected);
     /// ```
-    pub fn set(&mut self, value: T, row: usize, column: usize) -> Result<(), &'static str> {
-        let index = row*self.cols + column;
+    pub fn powi(&self, n: u32) -> Result<Matrix<T>, MatxError> {
 
-        if index >= self.data.len() {
-            Err("Index out of range")
+        if self.rows != self.cols {
+            return Err(MatxError::SizeError((self.rows, self.rows), (self.rows, self.cols)));
         }
-        else {
-            self.data[index] = value;
-            Ok(())
+
+        let mut out = Matrix::<T>::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            out.data[i*self.cols+i] = num::NumCast::from(1).unwrap();
         }
+
+        let mut base = self.clone();
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                out = (out * base.clone())?;
+            }
+
+            n >>= 1;
+
+            if n > 0 {
+                base = (base.clone() * base)?;
+            }
+        }
+
+        Ok(out)
     }
 
-    /// Method that returns a new, inverted matrix (same dimensions).
+    /// Method that computes the matrix product `self * rhs` like the `*` operator does, but walks through the matrices in `block`*`block` tiles to make a better use of the CPU cache on big matrices.
+    /// Each cell still accumulates its terms in the same order, so results are the same as with `*`.
+    /// 
+    /// A block size of 32 to 64 is a good start for `f64` matrices; a `block` of 0 is treated as 1.
     /// 
     /// # Examples
     /// 
@@ -230,36 +4149,51 @@ impl<T: Clone> Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
-    /// ]);
-    /// 
-    /// // Reversing the matrix and stocking the result
-    /// let rev = mat.reverse();
-    /// 
-    /// // What we're supposed to get
-    /// let rev_ = Matrix::<f64>::from(vec![
-    ///     vec![0.2f64, 1.2f64], 
-    ///     vec![3.6f64, 2.0f64]
-    /// ]);
+    /// let a = Matrix::<i64>::rand(40, 30, -10..10);
+    /// let b = Matrix::<i64>::rand(30, 50, -10..10);
     /// 
-    /// assert_eq!(rev, rev_);
+    /// assert_eq!(a.mul_blocked(&b, 16), a * b);
     /// ```
-    pub fn reverse(&self) -> Self {
-        Self {
-            data: self.data.clone().into_iter().rev().collect(),
-            rows: self.rows,
-            cols: self.cols
+    pub fn mul_blocked(&self, rhs: &Matrix<T>, block: usize) -> Result<Matrix<T>, MatxError> {
+
+        if self.cols != rhs.rows {
+            return Err(MatxError::DimensionMismatch(self.cols, rhs.rows));
         }
-    }
 
+        let block = block.max(1);
+        let mut out = Matrix::<T>::new(self.rows, rhs.cols);
+
+        for ii in (0..self.rows).step_by(block) {
+            for kk in (0..self.cols).step_by(block) {
+                for jj in (0..rhs.cols).step_by(block) {
+
+                    for i in ii..(ii+block).min(self.rows) {
+                        for k in kk..(kk+block).min(self.cols) {
+
+                            let a = self.data[i*self.cols+k].clone();
+
+                            for j in jj..(jj+block).min(rhs.cols) {
+                                out.data[i*rhs.cols+j] = out.data[i*rhs.cols+j].clone() + a.clone() * rhs.data[k*rhs.cols+j].clone();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(out)
+    }
 }
 
+/// Size under which `Matrix::mul_strassen()` stops recursing and falls back to the naive product.
+const STRASSEN_THRESHOLD: usize = 32;
 
-impl <T: rand::distributions::uniform::SampleUniform> Matrix<T> {
+impl<T> Matrix<T>
+where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + num::NumCast + Clone
+{
 
-    /// Sets all cells of the matrix to a random value in a certain range R.
+    /// Method that computes the matrix product `self * rhs` using Strassen's algorithm: square matrices are recursively split into quadrants, trading one of the eight block products for a few additions at each level.
+    /// Matrices that are not square, or whose size is odd or small enough, are multiplied with `*`; hence the algorithm pays off for big square matrices whose size is a power of two.
     /// 
     /// # Examples
     /// 
@@ -267,34 +4201,66 @@ impl <T: rand::distributions::uniform::SampleUniform> Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// // Building the randomized matrix
-    /// let mat = Matrix::<f64>::rand(5, 5, 0.0f64..10.0f64);
+    /// let a = Matrix::<i64>::rand(64, 64, -10..10);
+    /// let b = Matrix::<i64>::rand(64, 64, -10..10);
     /// 
-    /// // Printing the matrix
-    /// println!("{}", mat);
+    /// assert_eq!(a.mul_strassen(&b), a * b);
     /// ```
-    pub fn rand<R: rand::distributions::uniform::SampleRange<T> + Clone>(rows: usize, cols: usize, range: R) -> Self {
+    // `usize::is_multiple_of()` needs Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn mul_strassen(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatxError> {
 
-        let mut rng = rand::thread_rng();
+        if self.cols != rhs.rows {
+            return Err(MatxError::DimensionMismatch(self.cols, rhs.rows));
+        }
 
-        let mut data = Vec::<T>::new();
+        let n = self.rows;
 
-        for _i in 0..rows*cols {
-            data.push(rng.gen_range(range.clone()));
+        if n <= STRASSEN_THRESHOLD || n % 2 != 0 || !self.is_square() || !rhs.is_square() {
+            return self.clone() * rhs.clone();
         }
 
-        Self {
-            data,
-            rows,
-            cols
+        let (a11, a12, a21, a22) = self.split_quadrants()?;
+        let (b11, b12, b21, b22) = rhs.split_quadrants()?;
+
+        let m1 = (a11.clone() + a22.clone())?.mul_strassen(&(b11.clone() + b22.clone())?)?;
+        let m2 = (a21.clone() + a22.clone())?.mul_strassen(&b11)?;
+        let m3 = a11.mul_strassen(&(b12.clone() - b22.clone())?)?;
+        let m4 = a22.mul_strassen(&(b21.clone() - b11.clone())?)?;
+        let m5 = (a11.clone() + a12.clone())?.mul_strassen(&b22)?;
+        let m6 = (a21 - a11)?.mul_strassen(&(b11 + b12)?)?;
+        let m7 = (a12 - a22)?.mul_strassen(&(b21 + b22)?)?;
+
+        let c11 = (((m1.clone() + m4.clone())? - m5.clone())? + m7)?;
+        let c12 = (m3.clone() + m5)?;
+        let c21 = (m2.clone() + m4)?;
+        let c22 = (((m1 - m2)? + m3)? + m6)?;
+
+        // Stitching the quadrants back together
+        let half = n / 2;
+        let mut data = Vec::<T>::with_capacity(n*n);
+
+        for (left, right) in [(&c11, &c12), (&c21, &c22)] {
+            for i in 0..half {
+                data.extend_from_slice(&left.data[i*half..(i+1)*half]);
+                data.extend_from_slice(&right.data[i*half..(i+1)*half]);
+            }
         }
+
+        Ok(Matrix::<T> {
+            data,
+            rows: n,
+            cols: n
+        })
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T> Matrix<T>
+where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + num::NumCast + Clone + Send + Sync
+{
 
-impl<T: std::iter::Sum + Clone>  Matrix<T> {
-
-    /// Method that returns the sum of all cells in the matrix.
+    /// Method that computes the matrix product `self * rhs` like the `*` operator does, but spreads the output rows over several threads.
     /// 
     /// # Examples
     /// 
@@ -302,17 +4268,34 @@ impl<T: std::iter::Sum + Clone>  Matrix<T> {
     /// ```
     /// use matx::*;
     /// 
-    /// let mat = Matrix::<f64>::from(vec![
-    ///     vec![2.0f64, 3.6f64], 
-    ///     vec![1.2f64, 0.2f64]
-    /// ]);
-    /// 
-    /// let expected = 2.0f64 + 3.6f64 + 1.2f64 + 0.2f64;
+    /// let a = Matrix::<f64>::rand(16, 8, 0.0f64..1.0f64);
+    /// let b = Matrix::<f64>::rand(8, 16, 0.0f64..1.0f64);
     /// 
-    /// assert_eq!(expected, mat.sum());
+    /// assert_eq!(a.par_mul(&b), a * b);
     /// ```
-    pub fn sum(&self) -> T {
-        self.data.clone().into_iter().sum()
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatxError> {
+
+        use rayon::prelude::*;
+
+        if self.cols != rhs.rows {
+            return Err(MatxError::DimensionMismatch(self.cols, rhs.rows));
+        }
+
+        let mut out = Matrix::<T>::new(self.rows, rhs.cols);
+
+        if rhs.cols == 0 {
+            return Ok(out);
+        }
+
+        out.data.par_chunks_mut(rhs.cols).enumerate().for_each(|(i, row)| {
+            for (j, cell) in row.iter_mut().enumerate() {
+                for k in 0..self.cols {
+                    *cell = cell.clone() + self.data[i*self.cols+k].clone() * rhs.data[k*rhs.cols+j].clone();
+                }
+            }
+        });
+
+        Ok(out)
     }
 }
 
@@ -326,8 +4309,8 @@ where T: std::ops::Add<Output = T> + num::NumCast + Clone
 
     fn add(self, rhs: Matrix<T>) -> Self::Output {
 
-        if self.rows != rhs.rows || self.cols != rhs.cols {
-            Err(MatxError::SizeError)
+        if !self.same_shape(&rhs) {
+            Err(MatxError::SizeError((self.rows, self.cols), (rhs.rows, rhs.cols)))
         }
         else {
 
@@ -353,8 +4336,8 @@ where T: std::ops::Sub<Output = T> + num::NumCast + Clone
 
     fn sub(self, rhs: Matrix<T>) -> Self::Output {
 
-        if self.rows != rhs.rows || self.cols != rhs.cols {
-            Err(MatxError::SizeError)
+        if !self.same_shape(&rhs) {
+            Err(MatxError::SizeError((self.rows, self.cols), (rhs.rows, rhs.cols)))
         }
         else {
 
@@ -451,7 +4434,7 @@ where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + num::NumCast +
     fn mul(self, rhs: Matrix<T>) -> Self::Output {
         
         if self.cols != rhs.rows {
-            Err(MatxError::SizeError)
+            Err(MatxError::DimensionMismatch(self.cols, rhs.rows))
         }
         else {
 
@@ -501,7 +4484,7 @@ where T: std::ops::Div<Output = T> + std::ops::Add<Output = T> + num::NumCast +
     fn div(self, rhs: Matrix<T>) -> Self::Output {
         
         if self.cols != rhs.rows {
-            Err(MatxError::SizeError)
+            Err(MatxError::DimensionMismatch(self.cols, rhs.rows))
         }
         else {
 
@@ -540,6 +4523,99 @@ where T: std::ops::Div<Output = T> + num::NumCast + Clone
     }
 }
 
+// Mat_a % Mat_b
+impl<T> 
+ops::Rem<Matrix<T>> for Matrix<T> 
+where T: std::ops::Rem<Output = T> + num::NumCast + Clone
+{
+
+    type Output = Result<Matrix<T>, MatxError>;
+
+    fn rem(self, rhs: Matrix<T>) -> Self::Output {
+
+        if !self.same_shape(&rhs) {
+            Err(MatxError::SizeError((self.rows, self.cols), (rhs.rows, rhs.cols)))
+        }
+        else {
+
+            let mut out = Matrix::<T>::new(self.rows, self.cols);
+
+            for (i, (a, b)) in zip(self.data.iter(), rhs.data.iter()).enumerate(){
+                out.data[i] = a.to_owned() % b.to_owned();
+            }
+
+            Ok(out)
+        }
+
+    }
+}
+
+// Mat_a % b
+impl<T>
+ops::Rem<T> for Matrix<T>
+where T: std::ops::Rem<Output = T> + num::NumCast + Clone
+{
+    type Output = Matrix<T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        let mut out: Matrix<T> = Matrix::<T>::new(self.rows, self.cols);
+
+        for (i, val) in self.data.iter().enumerate() {
+                out.data[i] = val.to_owned() % rhs.clone();
+        }
+
+        out
+    }
+}
+
+impl<T>
+std::iter::Sum for Matrix<T>
+where T: std::ops::Add<Output = T> + num::NumCast + Clone
+{
+
+    /// Adds up a sequence of matrices, which must all have the same dimensions (panics otherwise). An empty sequence gives an empty 0*0 matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let batch = vec![Matrix::<f64>::ones(2, 2), Matrix::<f64>::ones(2, 2)];
+    /// 
+    /// let total: Matrix<f64> = batch.into_iter().sum();
+    /// 
+    /// assert_eq!(total, Matrix::filled(2, 2, 2.0f64));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, m| (acc + m).expect("all matrices must have the same dimensions")).unwrap_or_else(|| Matrix::<T>::new(0, 0))
+    }
+}
+
+impl<T>
+std::iter::Product for Matrix<T>
+where T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + num::NumCast + Clone
+{
+
+    /// Multiplies a sequence of matrices from left to right, as with `*`; dimensions must be compatible (panics otherwise). An empty sequence gives an empty 0*0 matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let chain = vec![Matrix::<f64>::ones(2, 3), Matrix::<f64>::ones(3, 1)];
+    /// 
+    /// let product: Matrix<f64> = chain.into_iter().product();
+    /// 
+    /// assert_eq!(product, Matrix::filled(2, 1, 3.0f64));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, m| (acc * m).expect("dimensions of consecutive matrices must be compatible")).unwrap_or_else(|| Matrix::<T>::new(0, 0))
+    }
+}
+
 impl <T> From<Vec<Vec<T>>> for Matrix<T> {
 
     /// Creates a matrix out of a vector of vectors. ROWS and COLS must be consistent with the data provided.
@@ -575,6 +4651,88 @@ impl <T> From<Vec<Vec<T>>> for Matrix<T> {
     }
 }
 
+impl<T> IntoIterator for Matrix<T> {
+
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the matrix into an iterator over its cells, in row-major order.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// for x in mat {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+
+    /// Consumes a matrix into a vector of rows, moving the cells rather than copying them.
+    fn from(value: Matrix<T>) -> Self {
+
+        let mut data = value.data.into_iter();
+
+        (0..value.rows).map(|_| data.by_ref().take(value.cols).collect()).collect()
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for Matrix<T> {
+
+    /// Creates a matrix out of an iterator of rows. All rows must have the same size.
+    /// 
+    /// # Examples
+    /// 
+    /// Basic usage:
+    /// ```
+    /// use matx::*;
+    /// 
+    /// let mat = Matrix::<f64>::from(vec![
+    ///     vec![2.0f64, 3.6f64], 
+    ///     vec![1.2f64, 0.2f64]
+    /// ]);
+    /// 
+    /// // Keeping only the rows that start with a positive value
+    /// let filtered: Matrix<f64> = mat.rows().filter(|r| r[0] > 1.5f64).collect();
+    /// 
+    /// assert_eq!(filtered, Matrix::<f64>::from(vec![vec![2.0f64, 3.6f64]]));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+
+        let mut data = Vec::<T>::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for mut row in iter {
+            if rows == 0 {
+                cols = row.len();
+            }
+
+            assert!(row.len() == cols); // all rows must have the same size
+            data.append(&mut row);
+            rows += 1;
+        }
+
+        Self {
+            data,
+            rows,
+            cols
+        }
+    }
+}
+
 /// An iterator over rows of a matrix.
 pub struct Rows<'a, T>(&'a Matrix<T>, usize, usize);
 
@@ -582,7 +4740,7 @@ pub struct Rows<'a, T>(&'a Matrix<T>, usize, usize);
 pub struct Columns<'a, T>(&'a Matrix<T>, usize, usize);
 
 // An iterator over cells of a matrix.
-pub struct Cells<'a, T>(&'a Matrix<T>, std::slice::Iter<'a, T>);
+pub struct Cells<'a, T>(std::slice::Iter<'a, T>);
 
 
 impl<T: Clone> Iterator for Columns<'_, T> {
@@ -591,12 +4749,13 @@ impl<T: Clone> Iterator for Columns<'_, T> {
 
     fn next(&mut self) -> Option<Vec<T>> {
 
-        if self.1*self.0.rows + self.2*self.0.rows < self.0.data.len() {
+        // Front and back cursors meet once all columns have been yielded
+        if self.1 + self.2 < self.0.cols {
 
             let mut out = Vec::<T>::new();
 
             for i in 0..self.0.rows {
-                let index = i*self.0.rows + self.1;
+                let index = i*self.0.cols + self.1;
                 out.push(self.0.data[index].clone());
             }
             self.1 += 1;
@@ -606,6 +4765,11 @@ impl<T: Clone> Iterator for Columns<'_, T> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.cols.saturating_sub(self.1 + self.2);
+        (remaining, Some(remaining))
+    }
 }
 
 
@@ -613,12 +4777,12 @@ impl<T: Clone> DoubleEndedIterator for Columns<'_, T> {
 
     fn next_back(&mut self) -> Option<Self::Item> {
 
-        if self.1*self.0.rows + self.2*self.0.rows < self.0.data.len() {
+        if self.1 + self.2 < self.0.cols {
 
             let mut out = Vec::<T>::new();
 
             for i in 0..self.0.rows {
-                let index = i*self.0.rows + (self.0.cols - self.2 - 1);
+                let index = i*self.0.cols + (self.0.cols - self.2 - 1);
                 out.push(self.0.data[index].clone());
             }
             self.2 += 1;
@@ -637,9 +4801,9 @@ impl<T: Clone> Iterator for Rows<'_, T> {
 
     fn next(&mut self) -> Option<Vec<T>> {
 
-        let index = self.1*self.0.cols;
-
-        if index + self.2*self.0.cols < self.0.data.len() {
+        // Front and back cursors meet once all rows have been yielded
+        if self.1 + self.2 < self.0.rows {
+            let index = self.1*self.0.cols;
             self.1 += 1;
             Some(self.0.data[index..index+self.0.cols].to_owned())
         }
@@ -647,6 +4811,11 @@ impl<T: Clone> Iterator for Rows<'_, T> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.rows.saturating_sub(self.1 + self.2);
+        (remaining, Some(remaining))
+    }
 }
 
 
@@ -654,9 +4823,8 @@ impl<T: Clone> DoubleEndedIterator for Rows<'_, T> {
 
     fn next_back(&mut self) -> Option<Vec<T>> {
 
-        let index = (self.0.rows - self.2 - 1)*self.0.cols;
-
-        if self.1*self.0.cols + self.2*self.0.cols < self.0.data.len() {
+        if self.1 + self.2 < self.0.rows {
+            let index = (self.0.rows - self.2 - 1)*self.0.cols;
             self.2 += 1;
             Some(self.0.data[index..index+self.0.cols].to_owned())
         }
@@ -673,7 +4841,12 @@ impl<'a, T: Clone> Iterator for Cells<'a, T> {
 
     fn next(&mut self) -> Option<&'a T> {
 
-        self.1.next()
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+
+        self.0.size_hint()
     }
 }
 
@@ -682,6 +4855,13 @@ impl<'a, T: Clone> DoubleEndedIterator for Cells<'a, T> {
 
     fn next_back(&mut self) -> Option<&'a T> {
 
-        self.1.next_back()
+        self.0.next_back()
     }
-}
\ No newline at end of file
+}
+
+
+impl<T: Clone> ExactSizeIterator for Rows<'_, T> {}
+
+impl<T: Clone> ExactSizeIterator for Columns<'_, T> {}
+
+impl<T: Clone> ExactSizeIterator for Cells<'_, T> {}
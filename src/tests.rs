@@ -324,4 +324,2280 @@ fn complex_mat() {
     ]);
 
     println!("{}", a);
-}
\ No newline at end of file
+}
+
+#[test]
+fn expect_shape() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    // Matching shape: the matrix itself is passed through
+    let sum = a.expect_shape(2, 3).unwrap().sum();
+    assert_eq!(sum, 21.0f64);
+
+    // Mismatch: the expected shape comes first, then the actual one
+    assert_eq!(a.expect_shape(3, 3), Err(MatxError::SizeError((3, 3), (2, 3))));
+}
+
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.5f64, 2.0f64, -3.0f64],
+        vec![4.0f64, 5.25f64, 6.0f64]
+    ]);
+
+    let mut buffer = Vec::<u8>::new();
+    a.to_csv(&mut buffer, b',').unwrap();
+
+    let b = Matrix::<f64>::from_csv(buffer.as_slice(), b',').unwrap();
+
+    assert_eq!(a, b);
+
+    // Ragged lines are refused
+    assert!(Matrix::<f64>::from_csv("1,2\n3\n".as_bytes(), b',').is_err());
+}
+
+
+#[test]
+fn sample_grid() {
+
+    let constant = Matrix::sample_grid(4, 3, (-1.0, 1.0), (0.0, 10.0), |_, _| 7.5f64);
+    assert_eq!(constant, Matrix::from(vec![vec![7.5f64; 3]; 4]));
+
+    // x goes along the columns, y along the rows
+    let linear = Matrix::sample_grid(3, 5, (0.0, 4.0), (-1.0, 1.0), |x, y| 2.0 * x + y);
+
+    assert_eq!((linear.rows, linear.cols), (3, 5));
+    assert_eq!(linear.get(0, 0).unwrap(), -1.0f64);
+    assert_eq!(linear.get(0, 4).unwrap(), 7.0f64);
+    assert_eq!(linear.get(2, 0).unwrap(), 1.0f64);
+    assert_eq!(linear.get(2, 4).unwrap(), 9.0f64);
+}
+
+
+#[test]
+fn powi() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 0.0f64],
+        vec![0.0f64, 1.0f64, 3.0f64],
+        vec![4.0f64, 0.0f64, 1.0f64]
+    ]);
+
+    let identity = Matrix::<f64>::from(vec![
+        vec![1.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, 1.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, 1.0f64]
+    ]);
+
+    assert_eq!(a.powi(0).unwrap(), identity);
+    assert_eq!(a.powi(1).unwrap(), a);
+
+    let a3 = ((a.clone() * a.clone()).unwrap() * a.clone()).unwrap();
+    assert_eq!(a.powi(3).unwrap(), a3);
+
+    // Only square matrices can be raised to a power
+    assert_eq!(Matrix::<f64>::new(2, 3).powi(2), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn symmetric() {
+
+    let sym = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![2.0f64, 5.0f64, 6.0f64],
+        vec![3.0f64, 6.0f64, 9.0f64]
+    ]);
+
+    let not_sym = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![2.0f64, 5.0f64, 6.0f64],
+        vec![3.0f64, 7.0f64, 9.0f64]
+    ]);
+
+    let not_square = Matrix::<f64>::new(2, 3);
+
+    assert!(sym.is_square() && sym.is_symmetric());
+    assert!(not_sym.is_square() && !not_sym.is_symmetric());
+    assert!(!not_square.is_square() && !not_square.is_symmetric());
+}
+
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_mul() {
+
+    let a = Matrix::<f64>::rand(64, 64, -1.0f64..1.0f64);
+    let b = Matrix::<f64>::rand(64, 64, -1.0f64..1.0f64);
+
+    assert_eq!(a.par_mul(&b).unwrap(), (a.clone() * b.clone()).unwrap());
+    assert!(a.par_mul(&Matrix::<f64>::new(3, 64)).is_err());
+}
+
+
+// Rough comparison of both versions on a bigger product, run with `cargo test --features rayon -- --ignored`
+#[cfg(feature = "rayon")]
+#[test]
+#[ignore]
+fn par_mul_timing() {
+
+    let a = Matrix::<f64>::rand(256, 256, -1.0f64..1.0f64);
+    let b = Matrix::<f64>::rand(256, 256, -1.0f64..1.0f64);
+
+    let start = std::time::Instant::now();
+    let serial = (a.clone() * b.clone()).unwrap();
+    println!("serial:   {:?}", start.elapsed());
+
+    let start = std::time::Instant::now();
+    let parallel = a.par_mul(&b).unwrap();
+    println!("parallel: {:?}", start.elapsed());
+
+    assert_eq!(serial, parallel);
+}
+
+
+#[test]
+fn mul_blocked() {
+
+    let a = Matrix::<i64>::rand(100, 100, -50..50);
+    let b = Matrix::<i64>::rand(100, 100, -50..50);
+
+    assert_eq!(a.mul_blocked(&b, 32).unwrap(), (a.clone() * b.clone()).unwrap());
+    assert_eq!(a.mul_blocked(&b, 7).unwrap(), (a.clone() * b.clone()).unwrap());
+
+    let a = Matrix::<f64>::rand(100, 100, -1.0f64..1.0f64);
+    let b = Matrix::<f64>::rand(100, 100, -1.0f64..1.0f64);
+
+    let naive = (a.clone() * b.clone()).unwrap();
+    let blocked = a.mul_blocked(&b, 32).unwrap();
+
+    for (x, y) in zip(naive.iter(), blocked.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+
+    assert!(a.mul_blocked(&Matrix::<f64>::new(3, 3), 32).is_err());
+}
+
+
+#[test]
+fn fill() {
+
+    let a = Matrix::filled(3, 4, 2.5f64);
+    assert_eq!((a.rows, a.cols), (3, 4));
+    assert!(a.iter().all(|x| *x == 2.5f64));
+
+    let mut b = Matrix::<f64>::rand(4, 2, 0.0f64..1.0f64);
+    b.fill(-1.0f64);
+    assert!(b.iter().all(|x| *x == -1.0f64));
+}
+
+
+#[test]
+fn swap() {
+
+    let mut a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    a.swap_rows(0, 2).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![7.0f64, 8.0f64, 9.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![1.0f64, 2.0f64, 3.0f64]
+    ]));
+
+    a.swap_cols(1, 2).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![7.0f64, 9.0f64, 8.0f64],
+        vec![4.0f64, 6.0f64, 5.0f64],
+        vec![1.0f64, 3.0f64, 2.0f64]
+    ]));
+
+    // Out of range indices leave the matrix untouched
+    let b = a.clone();
+    assert_eq!(a.swap_rows(1, 3), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(a.swap_cols(5, 0), Err(MatxError::IndexError(5, 3)));
+    assert_eq!(a, b);
+}
+
+
+#[test]
+fn map() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.0f64, 0.0f64],
+        vec![-4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    let mask = a.map(|x| x > 0.0f64);
+
+    assert_eq!(mask, Matrix::<bool>::from(vec![
+        vec![true, false, false],
+        vec![false, true, true]
+    ]));
+    assert_eq!((mask.rows, mask.cols), (2, 3));
+}
+
+
+#[test]
+fn row_column() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    assert_eq!(a.row(1).unwrap(), vec![4.0f64, 5.0f64, 6.0f64]);
+    assert_eq!(a.column(2).unwrap(), vec![3.0f64, 6.0f64, 9.0f64]);
+
+    assert_eq!(a.row(3), None);
+    assert_eq!(a.column(3), None);
+}
+
+
+#[test]
+fn flat_and_collect() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    let flat: Vec<f64> = a.iter().cloned().collect();
+    assert_eq!(Matrix::from_slice(2, 3, &flat).unwrap(), a);
+
+    let collected: Matrix<f64> = a.rows().collect();
+    assert_eq!(collected, a);
+
+    assert_eq!(Matrix::from_slice(3, 3, &flat), Err(MatxError::SizeError((3, 3), (1, 6))));
+    assert_eq!(Matrix::from_slice(1, 3, &flat[..4]), Err(MatxError::SizeError((1, 3), (1, 4))));
+}
+
+
+#[test]
+fn ones() {
+
+    let a = Matrix::<i32>::ones(3, 2);
+
+    assert_eq!((a.rows, a.cols), (3, 2));
+    assert!(a.iter().all(|x| *x == 1));
+}
+
+
+#[test]
+fn approx_eq() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64],
+        vec![3.0f64, 4.0f64]
+    ]);
+
+    let close = a.clone() + 1e-9f64;
+    let far = (a.clone() + Matrix::<f64>::from(vec![
+        vec![0.0f64, 0.0f64],
+        vec![0.0f64, 1e-3f64]
+    ])).unwrap();
+
+    assert!(a.approx_eq(&close, 1e-6));
+    assert!(!a.approx_eq(&far, 1e-6));
+    assert!(a.approx_eq(&far, 1e-2));
+
+    // Dimensions must match
+    assert!(!Matrix::<f64>::new(2, 3).approx_eq(&Matrix::<f64>::new(3, 2), 1.0));
+}
+
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode() {
+
+    let a = Matrix::<f64>::rand(100, 100, -1000.0f64..1000.0f64);
+
+    let bytes = a.to_bytes().unwrap();
+
+    // One u64 for the length of the data, the raw cells, then two u64 for the dimensions
+    assert_eq!(bytes.len(), 3*8 + 100*100*8);
+
+    assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), a);
+
+    assert!(Matrix::<f64>::from_bytes(&bytes[..50]).is_err());
+
+    // Dimensions that do not match the number of cells
+    let mut corrupted = Matrix::<f64>::ones(2, 2).to_bytes().unwrap();
+    let len = corrupted.len();
+    corrupted[len-8..].copy_from_slice(&3u64.to_le_bytes());
+
+    assert!(matches!(Matrix::<f64>::from_bytes(&corrupted), Err(MatxError::ParseError(_))));
+}
+
+
+#[test]
+fn lu() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![2.0f64, 1.0f64, 1.0f64, 0.0f64],
+        vec![4.0f64, 3.0f64, 3.0f64, 1.0f64],
+        vec![8.0f64, 7.0f64, 9.0f64, 5.0f64],
+        vec![6.0f64, 7.0f64, 9.0f64, 8.0f64]
+    ]);
+
+    let (l, u, p) = a.lu().unwrap();
+
+    for i in 0..4 {
+        assert_eq!(l.get(i, i).unwrap(), 1.0f64);
+
+        for j in i+1..4 {
+            assert_eq!(l.get(i, j).unwrap(), 0.0f64);
+            assert_eq!(u.get(j, i).unwrap(), 0.0f64);
+        }
+    }
+
+    // Rebuilding P*A
+    let mut pa = Matrix::<f64>::new(4, 4);
+    for (i, row) in p.iter().enumerate() {
+        for j in 0..4 {
+            pa.set(a.get(*row, j).unwrap(), i, j).unwrap();
+        }
+    }
+
+    assert!((l * u).unwrap().approx_eq(&pa, 1e-12));
+
+    assert_eq!(Matrix::<f64>::new(2, 3).lu(), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn solve() {
+
+    //  x + 2y + 3z = 14
+    // 2x -  y +  z =  3
+    // 3x +  y - 2z = -1
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![2.0f64, -1.0f64, 1.0f64],
+        vec![3.0f64, 1.0f64, -2.0f64]
+    ]);
+
+    let b = Matrix::<f64>::from(vec![
+        vec![14.0f64, 1.0f64],
+        vec![3.0f64, 0.0f64],
+        vec![-1.0f64, 2.0f64]
+    ]);
+
+    let x = a.solve(&b).unwrap();
+
+    assert!(x.column(0).unwrap().iter().zip([1.0f64, 2.0f64, 3.0f64]).all(|(a, b)| (a - b).abs() < 1e-12));
+    assert!((a.clone() * x).unwrap().approx_eq(&b, 1e-12));
+
+    // Errors
+    assert!(Matrix::<f64>::new(2, 3).solve(&b).is_err());
+    assert_eq!(a.solve(&Matrix::<f64>::new(2, 1)), Err(MatxError::SizeError((3, 1), (2, 1))));
+
+    let singular = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    assert_eq!(singular.solve(&b), Err(MatxError::SingularError));
+}
+
+
+#[test]
+fn norm() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.0f64, 3.0f64],
+        vec![-4.0f64, 5.0f64, -6.0f64]
+    ]);
+
+    // sqrt(1 + 4 + 9 + 16 + 25 + 36)
+    assert!((a.norm(NormKind::Frobenius) - 91.0f64.sqrt()).abs() < 1e-12);
+    // Columns: 5, 7, 9
+    assert_eq!(a.norm(NormKind::One), 9.0f64);
+    // Rows: 6, 15
+    assert_eq!(a.norm(NormKind::Inf), 15.0f64);
+}
+
+
+#[test]
+fn positions() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![9.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 8.0f64],
+        vec![7.0f64, 1.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(a.positions(|x| *x > 5.0f64), vec![(0, 0), (1, 2), (2, 0), (2, 2)]);
+    assert_eq!(a.count_where(|x| *x > 5.0f64), 4);
+    assert!(a.positions(|x| *x > 10.0f64).is_empty());
+}
+
+
+#[test]
+fn rotations() {
+
+    // 1 2 3
+    // 4 5 6
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(a.rotate90(), Matrix::<f64>::from(vec![
+        vec![4.0f64, 1.0f64],
+        vec![5.0f64, 2.0f64],
+        vec![6.0f64, 3.0f64]
+    ]));
+
+    assert_eq!(a.rotate180(), Matrix::<f64>::from(vec![
+        vec![6.0f64, 5.0f64, 4.0f64],
+        vec![3.0f64, 2.0f64, 1.0f64]
+    ]));
+
+    assert_eq!(a.flip_horizontal(), Matrix::<f64>::from(vec![
+        vec![3.0f64, 2.0f64, 1.0f64],
+        vec![6.0f64, 5.0f64, 4.0f64]
+    ]));
+
+    assert_eq!(a.flip_vertical(), Matrix::<f64>::from(vec![
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![1.0f64, 2.0f64, 3.0f64]
+    ]));
+
+    // Four quarter turns get us back to the start
+    assert_eq!(a.rotate90().rotate90().rotate90().rotate90(), a);
+}
+
+
+#[test]
+fn enumerate() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64],
+        vec![3.0f64, 4.0f64]
+    ]);
+
+    let cells: Vec<((usize, usize), &f64)> = a.enumerate().collect();
+
+    assert_eq!(cells, vec![((0, 0), &1.0f64), ((0, 1), &2.0f64), ((1, 0), &3.0f64), ((1, 1), &4.0f64)]);
+}
+
+
+#[test]
+fn kron() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2],
+        vec![3, 4]
+    ]);
+
+    let b = Matrix::<i32>::from(vec![
+        vec![0, 5],
+        vec![6, 7]
+    ]);
+
+    assert_eq!(a.kron(&b), Matrix::<i32>::from(vec![
+        vec![0, 5, 0, 10],
+        vec![6, 7, 12, 14],
+        vec![0, 15, 0, 20],
+        vec![18, 21, 24, 28]
+    ]));
+}
+
+
+#[test]
+fn diagonals() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    assert_eq!(a.diagonal(), vec![1.0f64, 5.0f64, 9.0f64]);
+    assert_eq!(a.anti_diagonal(), vec![3.0f64, 5.0f64, 7.0f64]);
+}
+
+
+#[test]
+fn softmax() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![-4.0f64, 50.0f64, 6.0f64],
+        vec![7.0f64, 7.0f64, 7.0f64]
+    ]);
+
+    let probs = a.softmax_rows();
+
+    for row in probs.rows() {
+        assert!((row.iter().sum::<f64>() - 1.0f64).abs() < 1e-12);
+    }
+
+    // A constant row gives uniform probabilities
+    for x in probs.row(2).unwrap() {
+        assert!((x - 1.0f64 / 3.0f64).abs() < 1e-12);
+    }
+
+    // Works on integer matrices as well
+    let b = Matrix::<i32>::from(vec![vec![1, 1]]).softmax_rows();
+    assert_eq!(b, Matrix::from(vec![vec![0.5f64, 0.5f64]]));
+}
+
+
+#[test]
+fn broadcast_add() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    assert_eq!(a.add_row_vector(&[10.0f64, 20.0f64, 30.0f64]).unwrap(), Matrix::<f64>::from(vec![
+        vec![11.0f64, 22.0f64, 33.0f64],
+        vec![14.0f64, 25.0f64, 36.0f64],
+        vec![17.0f64, 28.0f64, 39.0f64]
+    ]));
+
+    assert_eq!(a.add_col_vector(&[10.0f64, 20.0f64, 30.0f64]).unwrap(), Matrix::<f64>::from(vec![
+        vec![11.0f64, 12.0f64, 13.0f64],
+        vec![24.0f64, 25.0f64, 26.0f64],
+        vec![37.0f64, 38.0f64, 39.0f64]
+    ]));
+
+    assert_eq!(a.add_row_vector(&[1.0f64, 2.0f64]), Err(MatxError::SizeError((1, 3), (1, 2))));
+    assert_eq!(a.add_col_vector(&[1.0f64; 4]), Err(MatxError::SizeError((3, 1), (4, 1))));
+}
+
+
+#[test]
+fn display() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.5f64, 300.0f64],
+        vec![40.123f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(format!("{:.2}", a), " 1.00 -2.50 300.00\n40.12  5.00   6.00\n");
+    assert_eq!(format!("{}", a), "   1.0 -2.5 300.0\n40.123  5.0   6.0\n");
+    assert_eq!(format!("{:5}", Matrix::<i32>::from(vec![vec![1, 20]])), "    1    20\n");
+}
+
+
+#[test]
+fn nested() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(Matrix::from(a.to_nested()), a);
+
+    let nested: Vec<Vec<f64>> = a.clone().into();
+    assert_eq!(nested, a.to_nested());
+
+    // Rows without columns are kept
+    let b = Matrix::<f64>::new(3, 0);
+    assert_eq!(b.to_nested(), vec![Vec::<f64>::new(); 3]);
+    assert_eq!(Matrix::from(b.to_nested()), b);
+}
+
+
+#[test]
+fn rank() {
+
+    let full = Matrix::<f64>::from(vec![
+        vec![2.0f64, 1.0f64, 0.0f64],
+        vec![1.0f64, 3.0f64, 1.0f64],
+        vec![0.0f64, 1.0f64, 4.0f64]
+    ]);
+
+    let duplicated = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![1.0f64, 2.0f64, 3.0f64]
+    ]);
+
+    assert_eq!(full.rank(1e-10), 3);
+    assert_eq!(duplicated.rank(1e-10), 2);
+    assert_eq!(Matrix::<f64>::new(3, 4).rank(1e-10), 0);
+
+    // Non-square matrices
+    assert_eq!(Matrix::<f64>::from(vec![vec![1.0f64, 0.0f64, 0.0f64, 1.0f64], vec![0.0f64, 1.0f64, 0.0f64, 0.0f64]]).rank(1e-10), 2);
+}
+
+
+#[test]
+fn try_new() {
+
+    let a = Matrix::try_new(2, 3, vec![1.0f64, 2.0f64, 3.0f64, 4.0f64, 5.0f64, 6.0f64]).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]));
+
+    assert_eq!(Matrix::try_new(3, 2, vec![0.0f64; 5]), Err(MatxError::SizeError((3, 2), (1, 5))));
+    assert_eq!(Matrix::try_new(3, 2, vec![0.0f64; 7]), Err(MatxError::SizeError((3, 2), (1, 7))));
+}
+
+
+#[test]
+fn comparisons() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 5.0f64],
+        vec![3.0f64, 4.0f64]
+    ]);
+
+    let b = Matrix::<f64>::from(vec![
+        vec![2.0f64, 5.0f64],
+        vec![1.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(a.gt(&b).unwrap(), Matrix::from(vec![vec![false, false], vec![true, false]]));
+    assert_eq!(a.lt(&b).unwrap(), Matrix::from(vec![vec![true, false], vec![false, true]]));
+    assert_eq!(a.ge(&b).unwrap(), Matrix::from(vec![vec![false, true], vec![true, false]]));
+    assert_eq!(a.le(&b).unwrap(), Matrix::from(vec![vec![true, true], vec![false, true]]));
+
+    assert_eq!(a.gt(&Matrix::<f64>::new(2, 3)), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn select() {
+
+    let mask = Matrix::from(vec![
+        vec![true, false, true],
+        vec![false, true, false],
+        vec![true, false, true]
+    ]);
+
+    let a = Matrix::<i32>::filled(3, 3, 1);
+    let b = Matrix::<i32>::filled(3, 3, 2);
+
+    assert_eq!(Matrix::select(&mask, &a, &b).unwrap(), Matrix::<i32>::from(vec![
+        vec![1, 2, 1],
+        vec![2, 1, 2],
+        vec![1, 2, 1]
+    ]));
+
+    assert_eq!(Matrix::select(&mask, &a, &Matrix::<i32>::new(3, 2)), Err(MatxError::SizeError((3, 3), (3, 2))));
+}
+
+
+#[test]
+fn clamp() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![-5.0f64, 0.0f64, 2.5f64],
+        vec![10.0f64, -1.0f64, 1.0f64]
+    ]);
+
+    assert_eq!(a.clamp(-1.0f64, 2.0f64), Matrix::<f64>::from(vec![
+        vec![-1.0f64, 0.0f64, 2.0f64],
+        vec![2.0f64, -1.0f64, 1.0f64]
+    ]));
+}
+
+
+#[test]
+fn argmax() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 7.0f64, 3.0f64],
+        vec![-4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(a.argmax(), Some((0, 1)));
+    assert_eq!(a.argmin(), Some((1, 0)));
+
+    // Ties: the first cell in row-major order wins
+    let b = Matrix::<i32>::from(vec![
+        vec![0, 9, 0],
+        vec![9, 0, 9]
+    ]);
+
+    assert_eq!(b.argmax(), Some((0, 1)));
+    assert_eq!(b.argmin(), Some((0, 0)));
+
+    assert_eq!(Matrix::<f64>::new(0, 0).argmax(), None);
+}
+
+
+#[test]
+fn argmax_rows() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![0.1f64, 0.2f64, 0.3f64, 0.4f64],
+        vec![0.9f64, 0.0f64, 0.05f64, 0.05f64],
+        vec![0.2f64, 0.3f64, 0.3f64, 0.2f64]
+    ]);
+
+    assert_eq!(a.argmax_rows(), vec![3, 0, 1]);
+}
+
+
+#[test]
+fn fold() {
+
+    let a = Matrix::<i64>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6]
+    ]);
+
+    // 1 * 2 * 3 * 4 * 5 * 6
+    assert_eq!(a.fold(1, |acc, x| acc * x), 720);
+
+    // Row-major order
+    assert_eq!(a.fold(String::new(), |acc, x| acc + &x.to_string()), "123456");
+}
+
+
+#[test]
+fn iter_len() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    let mut rows = a.rows();
+    assert_eq!(rows.len(), 3);
+    rows.next();
+    assert_eq!(rows.size_hint(), (2, Some(2)));
+    rows.next_back();
+    assert_eq!(rows.len(), 1);
+    rows.next();
+    assert_eq!(rows.len(), 0);
+    assert_eq!(rows.next(), None);
+    assert_eq!(rows.len(), 0);
+
+    let mut cols = a.cols();
+    assert_eq!(cols.len(), 3);
+    cols.next_back();
+    assert_eq!(cols.size_hint(), (2, Some(2)));
+    cols.next();
+    cols.next();
+    assert_eq!(cols.len(), 0);
+
+    let mut cells = a.iter();
+    assert_eq!(cells.len(), 9);
+    cells.next();
+    cells.next_back();
+    assert_eq!(cells.size_hint(), (7, Some(7)));
+}
+
+
+// Alternates between next() and next_back(), checking every line is yielded exactly once
+fn check_no_overlap<I: DoubleEndedIterator<Item = Vec<f64>>>(mut iter: I, expected: Vec<Vec<f64>>) {
+
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+
+    while let Some(line) = iter.next() {
+        front.push(line);
+
+        match iter.next_back() {
+            Some(line) => back.push(line),
+            None => break
+        }
+    }
+
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    back.reverse();
+    front.append(&mut back);
+
+    assert_eq!(front, expected);
+}
+
+#[test]
+fn iter_no_overlap() {
+
+    for n in [4, 6] {
+        let a = Matrix::<f64>::try_new(n, n, (0..n*n).map(|x| x as f64).collect()).unwrap();
+
+        check_no_overlap(a.rows(), a.to_nested());
+        check_no_overlap(a.cols(), (0..n).map(|j| a.column(j).unwrap()).collect());
+    }
+
+    // Non-square matrices
+    let b = Matrix::<f64>::try_new(2, 3, vec![1.0f64, 2.0f64, 3.0f64, 4.0f64, 5.0f64, 6.0f64]).unwrap();
+
+    check_no_overlap(b.rows(), b.to_nested());
+    check_no_overlap(b.cols(), vec![vec![1.0f64, 4.0f64], vec![2.0f64, 5.0f64], vec![3.0f64, 6.0f64]]);
+}
+
+
+#[test]
+fn into_iter() {
+
+    let a = Matrix::<String>::from(vec![
+        vec![String::from("a"), String::from("b")],
+        vec![String::from("c"), String::from("d")]
+    ]);
+
+    let flat: Vec<String> = a.iter().cloned().collect();
+    let consumed: Vec<String> = a.into_iter().collect();
+
+    assert_eq!(consumed, flat);
+}
+
+
+#[test]
+fn like() {
+
+    let a = Matrix::<f64>::rand(3, 4, 0.0f64..1.0f64);
+
+    let zeros = Matrix::<i64>::zeros_like(&a);
+    let ones = Matrix::<f64>::ones_like(&a);
+
+    assert_eq!((zeros.rows, zeros.cols), (3, 4));
+    assert_eq!((ones.rows, ones.cols), (3, 4));
+    assert!(zeros.iter().all(|x| *x == 0));
+    assert!(ones.iter().all(|x| *x == 1.0f64));
+}
+
+
+#[test]
+fn qr() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![12.0f64, -51.0f64, 4.0f64],
+        vec![6.0f64, 167.0f64, -68.0f64],
+        vec![-4.0f64, 24.0f64, -41.0f64],
+        vec![1.0f64, 1.0f64, 1.0f64]
+    ]);
+
+    let (q, r) = a.qr().unwrap();
+
+    assert_eq!((q.rows, q.cols), (4, 3));
+    assert_eq!((r.rows, r.cols), (3, 3));
+
+    for i in 0..3 {
+        for j in 0..i {
+            assert_eq!(r.get(i, j).unwrap(), 0.0f64);
+        }
+    }
+
+    assert!((q.clone() * r).unwrap().approx_eq(&a, 1e-10));
+
+    let identity = Matrix::<f64>::from(vec![
+        vec![1.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, 1.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, 1.0f64]
+    ]);
+
+    assert!((q.transpose() * q).unwrap().approx_eq(&identity, 1e-12));
+
+    // Wide matrices and dependent columns
+    assert!(Matrix::<f64>::new(2, 3).qr().is_err());
+    assert_eq!(Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64], vec![2.0f64, 4.0f64]]).qr(), Err(MatxError::SingularError));
+}
+
+#[test]
+fn transpose() {
+
+    let a = Matrix::<f64>::rand(3, 5, 0.0f64..1.0f64);
+
+    assert_eq!((a.transpose().rows, a.transpose().cols), (5, 3));
+    assert_eq!(a.transpose().transpose(), a);
+    assert_eq!(a.transpose().row(2).unwrap(), a.column(2).unwrap());
+}
+
+
+#[test]
+fn lstsq() {
+
+    // Points around y = 3x - 2, with symmetric noise so that the fit is exact
+    let xs = [0.0f64, 1.0f64, 2.0f64, 3.0f64, 4.0f64, 5.0f64];
+    let noise = [0.1f64, -0.1f64, 0.0f64, 0.0f64, -0.1f64, 0.1f64];
+
+    let a = Matrix::try_new(6, 2, xs.iter().flat_map(|x| [*x, 1.0f64]).collect()).unwrap();
+    let y = Matrix::try_new(6, 1, zip(xs.iter(), noise.iter()).map(|(x, e)| 3.0f64 * x - 2.0f64 + e).collect()).unwrap();
+
+    let coefs = a.lstsq(&y).unwrap();
+
+    assert!((coefs.get(0, 0).unwrap() - 3.0f64).abs() < 1e-10);
+    assert!((coefs.get(1, 0).unwrap() + 2.0f64).abs() < 1e-10);
+
+    assert_eq!(a.lstsq(&Matrix::<f64>::new(5, 1)), Err(MatxError::SizeError((6, 1), (5, 1))));
+}
+
+
+#[test]
+fn cholesky() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![4.0f64, 12.0f64, -16.0f64],
+        vec![12.0f64, 37.0f64, -43.0f64],
+        vec![-16.0f64, -43.0f64, 98.0f64]
+    ]);
+
+    let l = a.cholesky().unwrap();
+
+    assert!(l.approx_eq(&Matrix::<f64>::from(vec![
+        vec![2.0f64, 0.0f64, 0.0f64],
+        vec![6.0f64, 1.0f64, 0.0f64],
+        vec![-8.0f64, 5.0f64, 3.0f64]
+    ]), 1e-12));
+
+    assert!((l.clone() * l.transpose()).unwrap().approx_eq(&a, 1e-12));
+
+    // Indefinite matrix (eigenvalues 3 and -1)
+    let indefinite = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64],
+        vec![2.0f64, 1.0f64]
+    ]);
+
+    assert_eq!(indefinite.cholesky(), Err(MatxError::DefinitenessError));
+    assert!(Matrix::<f64>::new(2, 3).cholesky().is_err());
+}
+
+
+#[test]
+fn dominant_eigen() {
+
+    // Eigenvalues 5 and 2, (1, 1) being the eigenvector for 5
+    let a = Matrix::<f64>::from(vec![
+        vec![4.0f64, 1.0f64],
+        vec![2.0f64, 3.0f64]
+    ]);
+
+    let (value, vector) = a.dominant_eigen(1000, 1e-14).unwrap();
+
+    assert!((value - 5.0f64).abs() < 1e-9);
+    assert!((vector.get(0, 0).unwrap() - vector.get(1, 0).unwrap()).abs() < 1e-6);
+    assert!(((a * vector.clone()).unwrap() / 5.0f64).approx_eq(&vector, 1e-6));
+
+    // The first Rayleigh quotient is 0 here, which must not pass for convergence
+    let diagonal = Matrix::<f64>::from(vec![
+        vec![3.0f64, 0.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, -1.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, -1.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, 0.0f64, -1.0f64]
+    ]);
+    let (value, _) = diagonal.dominant_eigen(1000, 1e-12).unwrap();
+    assert!((value - 3.0f64).abs() < 1e-9);
+
+    // The uniform start vector lies in the kernel, the dominant eigenvalue being 2
+    let singular = Matrix::<f64>::from(vec![
+        vec![1.0f64, -1.0f64],
+        vec![-1.0f64, 1.0f64]
+    ]);
+    let (value, vector) = singular.dominant_eigen(1000, 1e-12).unwrap();
+    assert!((value - 2.0f64).abs() < 1e-9);
+    assert!(((singular * vector.clone()).unwrap() / 2.0f64).approx_eq(&vector, 1e-6));
+
+    let (value, _) = Matrix::<f64>::new(3, 3).dominant_eigen(10, 1e-6).unwrap();
+    assert_eq!(value, 0.0f64);
+
+    assert_eq!(Matrix::<f64>::new(2, 3).dominant_eigen(10, 1e-6), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn scale() {
+
+    let mut a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]);
+
+    a.scale_row(0, 2.0f64).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![2.0f64, 4.0f64, 6.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 9.0f64]
+    ]));
+
+    a.scale_col(2, -1.0f64).unwrap();
+
+    assert_eq!(a.column(2).unwrap(), vec![-6.0f64, -6.0f64, -9.0f64]);
+
+    assert_eq!(a.scale_row(3, 2.0f64), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(a.scale_col(4, 2.0f64), Err(MatxError::IndexError(4, 3)));
+}
+
+
+#[test]
+fn add_scaled_row() {
+
+    let mut a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64],
+        vec![7.0f64, 8.0f64, 10.0f64]
+    ]);
+
+    // Eliminating the first cell of the second row
+    a.add_scaled_row(1, 0, -4.0f64).unwrap();
+
+    assert_eq!(a.row(1).unwrap(), vec![0.0f64, -3.0f64, -6.0f64]);
+    assert_eq!(a.row(0).unwrap(), vec![1.0f64, 2.0f64, 3.0f64]);
+
+    assert_eq!(a.add_scaled_row(3, 0, 1.0f64), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(a.add_scaled_row(0, 5, 1.0f64), Err(MatxError::IndexError(5, 3)));
+}
+
+
+#[test]
+fn reduce() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 9, 3],
+        vec![4, 5, 6],
+        vec![8, 7, 2]
+    ]);
+
+    let max = |cells: &[i32]| *cells.iter().max().unwrap();
+
+    assert_eq!(a.reduce_rows(max), vec![9, 6, 8]);
+    assert_eq!(a.reduce_cols(max), vec![8, 9, 6]);
+}
+
+
+#[test]
+fn set_diagonal() {
+
+    let mut a = Matrix::<f64>::ones(3, 3);
+
+    a.set_diagonal(&[5.0f64, 6.0f64, 7.0f64]).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![5.0f64, 1.0f64, 1.0f64],
+        vec![1.0f64, 6.0f64, 1.0f64],
+        vec![1.0f64, 1.0f64, 7.0f64]
+    ]));
+
+    assert_eq!(a.set_diagonal(&[0.0f64; 2]), Err(MatxError::SizeError((1, 3), (1, 2))));
+}
+
+
+#[test]
+fn resize() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64],
+        vec![3.0f64, 4.0f64]
+    ]);
+
+    let mut b = a.clone();
+    b.resize(3, 3, 9.0f64);
+
+    assert_eq!(b, Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 9.0f64],
+        vec![3.0f64, 4.0f64, 9.0f64],
+        vec![9.0f64, 9.0f64, 9.0f64]
+    ]));
+
+    b.resize(2, 2, 0.0f64);
+    assert_eq!(b, a);
+
+    b.resize(0, 2, 0.0f64);
+    assert_eq!((b.rows, b.cols, b.iter().count()), (0, 2, 0));
+}
+
+
+#[test]
+fn pad() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64],
+        vec![3.0f64, 4.0f64]
+    ]);
+
+    assert_eq!(a.pad(1, 1, 1, 1, 0.0f64), Matrix::<f64>::from(vec![
+        vec![0.0f64, 0.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, 1.0f64, 2.0f64, 0.0f64],
+        vec![0.0f64, 3.0f64, 4.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, 0.0f64, 0.0f64]
+    ]));
+
+    let b = a.pad(2, 0, 0, 1, -1.0f64);
+    assert_eq!((b.rows, b.cols), (4, 3));
+    assert_eq!(b.row(3).unwrap(), vec![3.0f64, 4.0f64, -1.0f64]);
+}
+
+
+#[test]
+fn convolve2d() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64, 4.0f64],
+        vec![5.0f64, 6.0f64, 7.0f64, 8.0f64],
+        vec![9.0f64, 10.0f64, 11.0f64, 12.0f64]
+    ]);
+
+    let blur = Matrix::<f64>::ones(3, 3);
+
+    // Borders only sum up the neighbours that exist
+    assert_eq!(a.convolve2d(&blur, ConvMode::Same), Matrix::<f64>::from(vec![
+        vec![14.0f64, 24.0f64, 30.0f64, 22.0f64],
+        vec![33.0f64, 54.0f64, 63.0f64, 45.0f64],
+        vec![30.0f64, 48.0f64, 54.0f64, 38.0f64]
+    ]));
+
+    assert_eq!(a.convolve2d(&blur, ConvMode::Valid), Matrix::<f64>::from(vec![
+        vec![54.0f64, 63.0f64]
+    ]));
+
+    // The kernel is flipped: a correlation would shift cells to the right instead
+    let shift = Matrix::<f64>::from(vec![vec![1.0f64, 0.0f64, 0.0f64]]);
+    assert_eq!(a.convolve2d(&shift, ConvMode::Same).row(0).unwrap(), vec![2.0f64, 3.0f64, 4.0f64, 0.0f64]);
+
+    // Empty kernels keep the shape of the input
+    for kernel in [Matrix::<f64>::new(0, 3), Matrix::<f64>::new(2, 0), Matrix::<f64>::new(0, 0)] {
+        assert_eq!(a.convolve2d(&kernel, ConvMode::Same), Matrix::new(3, 4));
+        assert_eq!(a.convolve2d(&kernel, ConvMode::Valid), Matrix::new(3, 4));
+    }
+}
+
+
+#[test]
+fn trace() {
+
+    let a = Matrix::<i64>::rand(3, 5, -10..10);
+    let b = Matrix::<i64>::rand(5, 3, -10..10);
+
+    assert_eq!(a.trace_of_product(&b).unwrap(), (a.clone() * b.clone()).unwrap().trace().unwrap());
+    assert_eq!(b.trace_of_product(&a).unwrap(), (b.clone() * a.clone()).unwrap().trace().unwrap());
+
+    assert_eq!(a.trace(), Err(MatxError::SizeError((3, 3), (3, 5))));
+    assert_eq!(a.trace_of_product(&a), Err(MatxError::SizeError((3, 5), (3, 5))));
+}
+
+
+#[test]
+fn sum_product() {
+
+    let batch = [
+        Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 4.0f64]]),
+        Matrix::<f64>::from(vec![vec![2.0f64, 2.0f64], vec![2.0f64, 2.0f64]]),
+        Matrix::<f64>::from(vec![vec![0.0f64, 5.0f64], vec![-2.0f64, 6.0f64]])
+    ];
+
+    let mean = batch.iter().cloned().sum::<Matrix<f64>>() / 3.0f64;
+
+    assert_eq!(mean, Matrix::<f64>::from(vec![vec![1.0f64, 3.0f64], vec![1.0f64, 4.0f64]]));
+
+    let product: Matrix<f64> = batch.iter().cloned().product();
+    let expected = ((batch[0].clone() * batch[1].clone()).unwrap() * batch[2].clone()).unwrap();
+
+    assert_eq!(product, expected);
+
+    assert_eq!(Vec::<Matrix<f64>>::new().into_iter().sum::<Matrix<f64>>(), Matrix::<f64>::new(0, 0));
+}
+
+#[test]
+#[should_panic]
+fn sum_mismatch() {
+
+    let _: Matrix<f64> = vec![Matrix::<f64>::new(2, 2), Matrix::<f64>::new(2, 3)].into_iter().sum();
+}
+
+
+#[test]
+fn cast() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, -2, 3],
+        vec![4, 5, 1000]
+    ]);
+
+    assert_eq!(a.cast::<f64>().unwrap(), Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 1000.0f64]
+    ]));
+
+    // Out of range for i8
+    assert_eq!(a.cast::<i8>(), None);
+    // Negative values do not fit in unsigned types
+    assert_eq!(Matrix::<i32>::from(vec![vec![-1]]).cast::<u32>(), None);
+}
+
+
+#[test]
+fn random_seeded() {
+
+    let a = Matrix::<f64>::rand_seeded(10, 10, -1.0f64..1.0f64, 1234);
+    let b = Matrix::<f64>::rand_seeded(10, 10, -1.0f64..1.0f64, 1234);
+    let c = Matrix::<f64>::rand_seeded(10, 10, -1.0f64..1.0f64, 4321);
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a.iter().all(|x| (-1.0f64..1.0f64).contains(x)));
+}
+
+
+#[test]
+fn randn() {
+
+    let a = Matrix::randn(200, 200, 3.0f64, 2.0f64);
+    let n = (a.rows * a.cols) as f64;
+
+    let mean = a.sum() / n;
+    let var = a.fold(0.0f64, |acc, x| acc + (x - mean).powi(2)) / n;
+
+    // The standard error of the mean is 2 / 200 = 0.01
+    assert!((mean - 3.0f64).abs() < 0.1f64);
+    assert!((var.sqrt() - 2.0f64).abs() < 0.1f64);
+}
+
+
+#[test]
+#[should_panic]
+fn randn_negative_std() {
+
+    Matrix::randn(2, 2, 0.0f64, -1.0f64);
+}
+
+
+#[test]
+#[should_panic]
+fn randn_nan_std() {
+
+    Matrix::randn(2, 2, 0.0f64, f64::NAN);
+}
+
+
+#[test]
+#[should_panic]
+fn randn_infinite_std() {
+
+    Matrix::randn(2, 2, 0.0f64, f64::INFINITY);
+}
+
+
+#[test]
+fn checked() {
+
+    let a = Matrix::<i8>::from(vec![
+        vec![120, 1],
+        vec![-120, 2]
+    ]);
+
+    let small = Matrix::<i8>::ones(2, 2);
+    let big = Matrix::<i8>::filled(2, 2, 10);
+
+    assert_eq!(a.checked_add(&small).unwrap(), (a.clone() + small.clone()).unwrap());
+    assert_eq!(a.checked_add(&big), Err(MatxError::OverflowError));
+    assert_eq!(a.checked_add(&-big.clone()), Err(MatxError::OverflowError));
+
+    assert_eq!(small.checked_mul(&small).unwrap(), Matrix::<i8>::filled(2, 2, 2));
+    assert_eq!(a.checked_mul(&big), Err(MatxError::OverflowError));
+
+    assert_eq!(a.checked_add(&Matrix::<i8>::new(1, 2)), Err(MatxError::SizeError((2, 2), (1, 2))));
+}
+
+
+#[test]
+fn sparse() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![0, 0, 3],
+        vec![0, 0, 0],
+        vec![7, 0, -1]
+    ]);
+
+    assert_eq!(a.nnz(), 3);
+    assert_eq!(a.to_triplets(), vec![(0, 2, 3), (2, 0, 7), (2, 2, -1)]);
+
+    assert_eq!(Matrix::<f64>::new(4, 4).nnz(), 0);
+}
+
+
+#[test]
+fn map_indexed() {
+
+    let a = Matrix::<f64>::rand(2, 3, 0.0f64..1.0f64);
+
+    assert_eq!(a.map_indexed(|i, j, _| i + j), Matrix::<usize>::from(vec![
+        vec![0, 1, 2],
+        vec![1, 2, 3]
+    ]));
+
+    // Values are passed along as well
+    assert_eq!(a.map_indexed(|_, _, x| *x), a);
+}
+
+
+#[test]
+fn same_shape() {
+
+    let a = Matrix::<f64>::new(2, 3);
+
+    assert!(a.same_shape(&Matrix::<f64>::rand(2, 3, 0.0f64..1.0f64)));
+    assert!(a.same_shape(&Matrix::from(vec![vec![true; 3]; 2])));
+    assert!(!a.same_shape(&a.transpose()));
+    assert!(!a.same_shape(&Matrix::<f64>::new(2, 4)));
+}
+
+
+#[test]
+fn cumsum() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert_eq!(a.cumsum_rows(), Matrix::<f64>::from(vec![
+        vec![1.0f64, 3.0f64, 6.0f64],
+        vec![4.0f64, 9.0f64, 15.0f64]
+    ]));
+
+    assert_eq!(a.cumsum_cols(), Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![5.0f64, 7.0f64, 9.0f64]
+    ]));
+}
+
+
+#[test]
+fn scale_by() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6]
+    ]);
+
+    assert_eq!(a.scale_rows_by(&[2, -1]).unwrap(), Matrix::<i32>::from(vec![
+        vec![2, 4, 6],
+        vec![-4, -5, -6]
+    ]));
+
+    assert_eq!(a.scale_cols_by(&[0, 1, 10]).unwrap(), Matrix::<i32>::from(vec![
+        vec![0, 2, 30],
+        vec![0, 5, 60]
+    ]));
+
+    assert_eq!(a.scale_rows_by(&[1, 2, 3]), Err(MatxError::SizeError((2, 1), (3, 1))));
+    assert_eq!(a.scale_cols_by(&[1, 2]), Err(MatxError::SizeError((1, 3), (1, 2))));
+}
+
+
+#[test]
+fn outer() {
+
+    assert_eq!(Matrix::outer(&[1.0f64, -2.0f64, 3.0f64], &[4.0f64, 0.5f64]), Matrix::<f64>::from(vec![
+        vec![4.0f64, 0.5f64],
+        vec![-8.0f64, -1.0f64],
+        vec![12.0f64, 1.5f64]
+    ]));
+}
+
+
+#[test]
+fn dot() {
+
+    let row = Matrix::<f64>::from(vec![vec![1.0f64, -2.0f64, 0.5f64]]);
+    let col = Matrix::<f64>::from(vec![vec![4.0f64], vec![1.0f64], vec![2.0f64]]);
+
+    assert_eq!(row.dot(&col).unwrap(), 3.0f64);
+    assert_eq!(col.dot(&col).unwrap(), 21.0f64);
+
+    // Lengths differ
+    assert_eq!(row.dot(&Matrix::<f64>::ones(2, 1)), Err(MatxError::SizeError((1, 3), (2, 1))));
+    // Not a vector
+    assert_eq!(Matrix::<f64>::ones(3, 3).dot(&col), Err(MatxError::SizeError((3, 3), (3, 1))));
+}
+
+
+#[test]
+fn structure() {
+
+    let diag = Matrix::<f64>::from(vec![
+        vec![1.0f64, 0.0f64, 0.0f64],
+        vec![0.0f64, 2.0f64, 0.0f64],
+        vec![0.0f64, 0.0f64, 3.0f64]
+    ]);
+
+    assert!(diag.is_diagonal() && diag.is_upper_triangular() && diag.is_lower_triangular());
+
+    let dense = Matrix::<f64>::ones(3, 3);
+
+    assert!(!dense.is_diagonal() && !dense.is_upper_triangular() && !dense.is_lower_triangular());
+
+    let (_, u, _) = Matrix::<f64>::from(vec![
+        vec![2.0f64, 1.0f64, 1.0f64],
+        vec![4.0f64, 3.0f64, 3.0f64],
+        vec![8.0f64, 7.0f64, 9.0f64]
+    ]).lu().unwrap();
+
+    assert!(u.is_upper_triangular() && !u.is_lower_triangular());
+    assert!(u.transpose().is_lower_triangular());
+}
+
+
+#[test]
+fn solve_triangular() {
+
+    let upper = Matrix::<f64>::from(vec![
+        vec![2.0f64, -1.0f64, 3.0f64],
+        vec![0.0f64, 4.0f64, 1.0f64],
+        vec![0.0f64, 0.0f64, 5.0f64]
+    ]);
+
+    let lower = upper.transpose();
+    let x = Matrix::<f64>::from(vec![vec![1.0f64], vec![-2.0f64], vec![3.0f64]]);
+
+    let b = (upper.clone() * x.clone()).unwrap();
+    assert!(upper.solve_triangular(&b, true).unwrap().approx_eq(&x, 1e-12));
+
+    let b = (lower.clone() * x.clone()).unwrap();
+    assert!(lower.solve_triangular(&b, false).unwrap().approx_eq(&x, 1e-12));
+
+    let mut singular = upper.clone();
+    singular.set(0.0f64, 1, 1).unwrap();
+
+    assert_eq!(singular.solve_triangular(&b, true), Err(MatxError::SingularError));
+    assert_eq!(upper.solve_triangular(&Matrix::<f64>::new(2, 1), true), Err(MatxError::SizeError((3, 1), (2, 1))));
+}
+
+
+#[test]
+fn zip_map() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 5.0f64, -2.0f64],
+        vec![0.5f64, 3.0f64, 8.0f64]
+    ]);
+
+    let b = Matrix::<f64>::from(vec![
+        vec![2.0f64, 4.0f64, -3.0f64],
+        vec![0.5f64, 7.0f64, 1.0f64]
+    ]);
+
+    let max = a.zip_map(&b, |x, y| x.max(*y)).unwrap();
+
+    assert_eq!(max, Matrix::from(vec![
+        vec![2.0f64, 5.0f64, -2.0f64],
+        vec![0.5f64, 7.0f64, 8.0f64]
+    ]));
+
+    // Cells of both matrices do not need to share the same type
+    let flags = a.zip_map(&b.clone().map(|x| x as i32), |x, y| *x > *y as f64).unwrap();
+    assert_eq!(flags.row(0).unwrap(), vec![false, true, true]);
+
+    assert_eq!(a.zip_map(&b.transpose(), |x, y| x + y), Err(MatxError::SizeError((2, 3), (3, 2))));
+}
+
+
+#[test]
+fn rand_with() {
+
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+    let a = Matrix::<f64>::rand_with(&mut rng, 10, 10, -1.0f64..1.0f64);
+    let b = Matrix::<f64>::rand_with(&mut rng, 10, 10, -1.0f64..1.0f64);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+    assert_eq!(Matrix::<f64>::rand_with(&mut rng, 10, 10, -1.0f64..1.0f64), a);
+    assert_eq!(Matrix::<f64>::rand_with(&mut rng, 10, 10, -1.0f64..1.0f64), b);
+    assert_ne!(a, b);
+
+    // Same generator as `rand_seeded`
+    assert_eq!(Matrix::<f64>::rand_seeded(10, 10, -1.0f64..1.0f64, 1234), a);
+
+    // Trait objects can be provided too
+    let rng: &mut dyn rand::RngCore = &mut rand::rngs::StdRng::seed_from_u64(1234);
+    assert_eq!(Matrix::<f64>::rand_with(rng, 10, 10, -1.0f64..1.0f64), a);
+}
+
+
+#[test]
+fn split_quadrants() {
+
+    let mat = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64, 4.0f64],
+        vec![5.0f64, 6.0f64, 7.0f64, 8.0f64],
+        vec![9.0f64, 10.0f64, 11.0f64, 12.0f64],
+        vec![13.0f64, 14.0f64, 15.0f64, 16.0f64]
+    ]);
+
+    let (a, b, c, d) = mat.split_quadrants().unwrap();
+
+    assert_eq!(a, Matrix::from(vec![vec![1.0f64, 2.0f64], vec![5.0f64, 6.0f64]]));
+    assert_eq!(b, Matrix::from(vec![vec![3.0f64, 4.0f64], vec![7.0f64, 8.0f64]]));
+    assert_eq!(c, Matrix::from(vec![vec![9.0f64, 10.0f64], vec![13.0f64, 14.0f64]]));
+    assert_eq!(d, Matrix::from(vec![vec![11.0f64, 12.0f64], vec![15.0f64, 16.0f64]]));
+
+    assert_eq!(Matrix::<f64>::new(3, 4).split_quadrants(), Err(MatxError::SizeError((4, 4), (3, 4))));
+}
+
+
+#[test]
+fn mul_strassen() {
+
+    let a = Matrix::<i64>::rand(64, 64, -100..100);
+    let b = Matrix::<i64>::rand(64, 64, -100..100);
+
+    assert_eq!(a.mul_strassen(&b).unwrap(), (a.clone() * b.clone()).unwrap());
+
+    let a = Matrix::<f64>::rand(64, 64, -1.0f64..1.0f64);
+    let b = Matrix::<f64>::rand(64, 64, -1.0f64..1.0f64);
+
+    assert!(a.mul_strassen(&b).unwrap().approx_eq(&(a.clone() * b.clone()).unwrap(), 1e-9));
+
+    // Falls back to the naive product for other shapes
+    let c = Matrix::<i64>::rand(64, 10, -100..100);
+    let d = Matrix::<i64>::rand(64, 64, -100..100);
+    assert_eq!(d.mul_strassen(&c).unwrap(), (d.clone() * c.clone()).unwrap());
+
+    assert_eq!(c.mul_strassen(&c), Err(MatxError::DimensionMismatch(10, 64)));
+}
+
+
+#[test]
+fn map_rows() {
+
+    let mat = Matrix::<f64>::from(vec![
+        vec![1.0f64, 3.0f64, 4.0f64],
+        vec![2.0f64, 2.0f64, 4.0f64]
+    ]);
+
+    let normalized = mat.map_rows(|row| {
+        let total = row.iter().sum::<f64>();
+        row.iter().map(|x| x / total).collect()
+    });
+
+    assert_eq!(normalized, Matrix::from(vec![
+        vec![0.125f64, 0.375f64, 0.5f64],
+        vec![0.25f64, 0.25f64, 0.5f64]
+    ]));
+
+    for row in normalized.rows() {
+        assert!((row.iter().sum::<f64>() - 1.0f64).abs() < 1e-12);
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn map_rows_resized() {
+
+    Matrix::<f64>::ones(2, 3).map_rows(|row| row[1..].to_vec());
+}
+
+
+#[test]
+fn pretty_string() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.5f64],
+        vec![40.125f64, 5.0f64]
+    ]);
+
+    assert_eq!(a.to_pretty_string(), "[    1.0 -2.5 ]\n[ 40.125  5.0 ]\n");
+    assert_eq!(Matrix::<i32>::from(vec![vec![1, 2]]).to_pretty_string(), "[ 1 2 ]\n");
+    assert_eq!(Matrix::<i32>::new(0, 0).to_pretty_string(), "");
+}
+
+
+#[cfg(feature = "npy")]
+#[test]
+fn npy() {
+
+    let a = Matrix::<f64>::rand(7, 13, -1000.0f64..1000.0f64);
+
+    let mut buffer = Vec::<u8>::new();
+    a.to_npy(&mut buffer).unwrap();
+
+    // The preamble is padded to a multiple of 64 bytes, followed by the raw cells
+    assert_eq!(&buffer[..10], b"\x93NUMPY\x01\x00\x76\x00");
+    assert!(String::from_utf8_lossy(&buffer[10..128]).starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (7, 13), }"));
+    assert_eq!(buffer[127], b'\n');
+    assert_eq!(buffer.len(), 128 + 7*13*8);
+
+    assert_eq!(Matrix::<f64>::from_npy(buffer.as_slice()).unwrap(), a);
+
+    // Fortran-ordered array, as written by NumPy
+    let mut fortran = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+    let header = "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 2), }";
+    fortran.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+    for x in [1.0f64, 3.0f64, 2.0f64, 4.0f64] {
+        fortran.extend_from_slice(&x.to_le_bytes());
+    }
+
+    assert_eq!(Matrix::<f64>::from_npy(fortran.as_slice()).unwrap(), Matrix::from(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 4.0f64]]));
+
+    assert!(Matrix::<f64>::from_npy(&buffer[..100]).is_err());
+    assert!(Matrix::<f64>::from_npy(&buffer[..200]).is_err());
+    assert!(Matrix::<f64>::from_npy(&b"\x93NUMPY\x02\x00\xff\xff\xff\xff{'descr'"[..]).is_err());
+    assert!(Matrix::<f64>::from_npy("not a npy file".as_bytes()).is_err());
+
+    // Shapes announced by the header are not trusted
+    for shape in ["(18446744073709551615, 2)", "(1000000000, 1000000000)"] {
+        let mut crafted = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+        let header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}", shape);
+        crafted.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+        crafted.extend_from_slice(&1.0f64.to_le_bytes());
+
+        assert!(matches!(Matrix::<f64>::from_npy(crafted.as_slice()), Err(MatxError::ParseError(_))));
+    }
+}
+
+
+#[test]
+fn col_variance() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![2, 0, 7],
+        vec![4, 0, 7],
+        vec![4, 0, 7],
+        vec![4, 0, 7],
+        vec![5, 6, 7],
+        vec![5, 6, 7],
+        vec![7, 6, 7],
+        vec![9, 6, 7]
+    ]);
+
+    assert_eq!(a.col_variance(), vec![4.0f64, 9.0f64, 0.0f64]);
+    assert_eq!(a.col_std(), vec![2.0f64, 3.0f64, 0.0f64]);
+
+    // Without rows, every column has an undefined variance
+    let empty = Matrix::<i32>::new(0, 3);
+    assert!(empty.col_variance().len() == 3 && empty.col_variance().iter().all(|v| v.is_nan()));
+    assert!(empty.col_std().len() == 3 && empty.col_std().iter().all(|v| v.is_nan()));
+    assert_eq!(empty.standardize_cols(), Matrix::<f64>::new(0, 3));
+}
+
+
+#[test]
+fn standardize_cols() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![2.0f64, -3.0f64, 1.0f64],
+        vec![4.0f64, 10.0f64, 1.0f64],
+        vec![9.0f64, 0.5f64, 1.0f64],
+        vec![1.0f64, 7.0f64, 1.0f64]
+    ]);
+
+    let z = a.standardize_cols();
+
+    for (j, col) in z.cols().enumerate().take(2) {
+        let n = col.len() as f64;
+        let mean = col.iter().sum::<f64>() / n;
+        let std = (col.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+        assert!(mean.abs() < 1e-12, "column {} has mean {}", j, mean);
+        assert!((std - 1.0f64).abs() < 1e-12, "column {} has std {}", j, std);
+    }
+
+    // Constant columns are left as is
+    assert_eq!(z.column(2).unwrap(), vec![1.0f64; 4]);
+}
+
+
+#[test]
+fn equals_transpose() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    let b = a.transpose();
+
+    assert!(a.equals_transpose(&b));
+    assert!(b.equals_transpose(&a));
+
+    let mut c = b.clone();
+    c.set(0.0f64, 2, 1).unwrap();
+    assert!(!a.equals_transpose(&c));
+
+    // Symmetric matrices are their own transpose
+    assert!(Matrix::<f64>::ones(3, 3).equals_transpose(&Matrix::ones(3, 3)));
+    assert!(!a.equals_transpose(&a));
+}
+
+
+#[test]
+fn from_rows() {
+
+    let a = Matrix::from_rows((0..3).map(|i| (0..4).map(move |j| (i*4 + j) as f64))).unwrap();
+
+    assert_eq!(a, Matrix::<f64>::from(vec![
+        vec![0.0f64, 1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64, 7.0f64],
+        vec![8.0f64, 9.0f64, 10.0f64, 11.0f64]
+    ]));
+
+    assert_eq!(Matrix::<f64>::from_rows(Vec::<Vec<f64>>::new()).unwrap(), Matrix::new(0, 0));
+    assert_eq!(Matrix::from_rows((1..4).map(|i| 0..i)), Err(MatxError::SizeError((1, 1), (1, 2))));
+}
+
+
+#[test]
+fn dimension_mismatch() {
+
+    let a = Matrix::<f64>::ones(2, 3);
+    let b = Matrix::<f64>::ones(4, 5);
+
+    // Columns of the left-hand side first, then rows of the right-hand side
+    assert_eq!(a.clone() * b.clone(), Err(MatxError::DimensionMismatch(3, 4)));
+    assert_eq!(b.clone() * a.clone(), Err(MatxError::DimensionMismatch(5, 2)));
+    assert_eq!(a.mul_blocked(&b, 8), Err(MatxError::DimensionMismatch(3, 4)));
+    assert_eq!(Matrix::<i32>::ones(2, 3).checked_mul(&Matrix::ones(2, 3)), Err(MatxError::DimensionMismatch(3, 2)));
+}
+
+
+#[test]
+fn transpose_in_place() {
+
+    let a = Matrix::<f64>::rand(5, 5, -1.0f64..1.0f64);
+
+    let mut b = a.clone();
+    b.transpose_in_place().unwrap();
+    assert_eq!(b, a.transpose());
+
+    b.transpose_in_place().unwrap();
+    assert_eq!(b, a);
+
+    let mut c = Matrix::<f64>::new(2, 3);
+    assert_eq!(c.transpose_in_place(), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn value_counts() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![0, 2, 2, 1],
+        vec![2, 0, 3, 2],
+        vec![1, 2, 0, 0]
+    ]);
+
+    let counts = a.value_counts();
+
+    assert_eq!(counts.len(), 4);
+    assert_eq!(counts[&0], 4);
+    assert_eq!(counts[&1], 2);
+    assert_eq!(counts[&2], 5);
+    assert_eq!(counts[&3], 1);
+    assert_eq!(counts.values().sum::<usize>(), a.rows * a.cols);
+
+    assert!(Matrix::<i32>::new(0, 0).value_counts().is_empty());
+}
+
+
+#[test]
+fn replace() {
+
+    let mut a = Matrix::<f64>::from(vec![
+        vec![0.0f64, 2.0f64, 0.0f64],
+        vec![1.5f64, 0.0f64, 3.0f64]
+    ]);
+
+    assert_eq!(a.replace(&0.0f64, 9.0f64), 3);
+
+    assert_eq!(a, Matrix::from(vec![
+        vec![9.0f64, 2.0f64, 9.0f64],
+        vec![1.5f64, 9.0f64, 3.0f64]
+    ]));
+
+    assert_eq!(a.replace(&0.0f64, 9.0f64), 0);
+}
+
+
+#[test]
+fn any_all() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![4.0f64, 5.0f64, 6.0f64]
+    ]);
+
+    assert!(a.all(|x| *x > 0.0f64));
+    assert!(!a.all(|x| *x > 1.0f64));
+
+    assert!(a.any(|x| *x == 5.0f64));
+    assert!(!a.any(|x| x.is_nan()));
+
+    let mut b = a.clone();
+    b.set(f64::NAN, 1, 2).unwrap();
+    assert!(b.any(|x| x.is_nan()));
+    assert!(!b.all(|x| x.is_finite()));
+
+    let empty = Matrix::<f64>::new(0, 0);
+    assert!(empty.all(|x| x.is_nan()));
+    assert!(!empty.any(|x| x.is_nan()));
+}
+
+
+#[test]
+fn is_identity() {
+
+    let mut a = Matrix::<f64>::new(3, 3);
+    a.set_diagonal(&[1.0f64; 3]).unwrap();
+
+    assert!(a.is_identity(0.0f64));
+    assert!(Matrix::<i32>::from(vec![vec![1, 0], vec![0, 1]]).is_identity(0.0f64));
+
+    a.set(1.0f64 + 1e-9, 1, 1).unwrap();
+    a.set(-1e-9, 0, 2).unwrap();
+
+    assert!(!a.is_identity(1e-12));
+    assert!(a.is_identity(1e-6));
+
+    assert!(!Matrix::<f64>::ones(3, 3).is_identity(1e-6));
+    assert!(!Matrix::<f64>::new(2, 3).is_identity(1.0f64));
+}
+
+
+#[test]
+fn flat() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6]
+    ]);
+
+    assert_eq!(a.as_flat(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(a.transpose().as_flat(), &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(a.as_flat().len(), a.rows * a.cols);
+
+    let (rows, cols) = (a.rows, a.cols);
+    assert_eq!(Matrix::try_new(rows, cols, a.clone().into_flat()).unwrap(), a);
+}
+
+
+#[test]
+fn tile() {
+
+    let a = Matrix::<f64>::from(vec![vec![1.0f64, 2.0f64]]);
+
+    assert_eq!(a.tile(2, 2), Matrix::from(vec![
+        vec![1.0f64, 2.0f64, 1.0f64, 2.0f64],
+        vec![1.0f64, 2.0f64, 1.0f64, 2.0f64]
+    ]));
+
+    let b = Matrix::<i32>::from(vec![vec![1, 2], vec![3, 4]]);
+
+    assert_eq!(b.tile(2, 1), Matrix::from(vec![vec![1, 2], vec![3, 4], vec![1, 2], vec![3, 4]]));
+    assert_eq!(b.tile(1, 1), b);
+    assert_eq!(b.tile(0, 3), Matrix::new(0, 6));
+}
+
+
+#[test]
+fn minor() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 9]
+    ]);
+
+    let m = a.minor(1, 0).unwrap();
+
+    assert_eq!((m.rows, m.cols), (2, 2));
+    assert_eq!(m, Matrix::from(vec![vec![2, 3], vec![8, 9]]));
+
+    assert_eq!(a.minor(2, 2).unwrap(), Matrix::from(vec![vec![1, 2], vec![4, 5]]));
+    assert_eq!(a.minor(3, 0), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(a.minor(0, 5), Err(MatxError::IndexError(5, 3)));
+}
+
+
+#[test]
+fn adjugate() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, 2.0f64, 3.0f64],
+        vec![0.0f64, 1.0f64, 4.0f64],
+        vec![5.0f64, 6.0f64, 0.0f64]
+    ]);
+
+    assert!((a.determinant().unwrap() - 1.0f64).abs() < 1e-12);
+
+    let adj = a.adjugate().unwrap();
+
+    assert!(adj.approx_eq(&Matrix::from(vec![
+        vec![-24.0f64, 18.0f64, 5.0f64],
+        vec![20.0f64, -15.0f64, -4.0f64],
+        vec![-5.0f64, 4.0f64, 1.0f64]
+    ]), 1e-12));
+
+    let b = Matrix::<f64>::from(vec![
+        vec![2.0f64, -1.0f64, 0.5f64],
+        vec![4.0f64, 3.0f64, 1.0f64],
+        vec![-2.0f64, 1.0f64, 7.0f64]
+    ]);
+
+    let det = b.determinant().unwrap();
+    let mut scaled_identity = Matrix::<f64>::new(3, 3);
+    scaled_identity.set_diagonal(&[det; 3]).unwrap();
+
+    assert!((b.clone() * b.adjugate().unwrap()).unwrap().approx_eq(&scaled_identity, 1e-9));
+
+    // Swapping two rows flips the sign of the determinant
+    let mut c = b.clone();
+    c.swap_rows(0, 2).unwrap();
+    assert!((c.determinant().unwrap() + det).abs() < 1e-9);
+
+    assert_eq!(Matrix::<f64>::new(2, 3).adjugate(), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn diagonal_iter() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12]
+    ]);
+
+    assert_eq!(a.diagonal_iter().sum::<i32>(), 1 + 6 + 11);
+    assert_eq!(a.transpose().diagonal_iter().collect::<Vec<&i32>>(), vec![&1, &6, &11]);
+
+    let b = Matrix::<f64>::rand(6, 6, -1.0f64..1.0f64);
+    assert_eq!(b.diagonal_iter().sum::<f64>(), b.trace().unwrap());
+
+    assert_eq!(Matrix::<i32>::new(0, 3).diagonal_iter().count(), 0);
+    assert_eq!(Matrix::<i32>::new(3, 0).diagonal_iter().count(), 0);
+}
+
+
+#[test]
+fn swap_cells() {
+
+    let mut a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6]
+    ]);
+
+    a.swap((0, 2), (1, 0)).unwrap();
+    assert_eq!(a, Matrix::from(vec![vec![1, 2, 4], vec![3, 5, 6]]));
+
+    a.swap((1, 1), (1, 1)).unwrap();
+    assert_eq!(a.get(1, 1).unwrap(), 5);
+
+    assert_eq!(a.swap((2, 0), (0, 0)), Err(MatxError::IndexError(2, 2)));
+    assert_eq!(a.swap((0, 0), (1, 3)), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(a, Matrix::from(vec![vec![1, 2, 4], vec![3, 5, 6]]));
+}
+
+
+#[test]
+fn elementwise_max_min() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -5.0f64],
+        vec![2.5f64, 0.0f64]
+    ]);
+
+    let b = Matrix::<f64>::from(vec![
+        vec![0.5f64, -2.0f64],
+        vec![2.5f64, 3.0f64]
+    ]);
+
+    assert_eq!(a.elementwise_max(&b).unwrap(), Matrix::from(vec![vec![1.0f64, -2.0f64], vec![2.5f64, 3.0f64]]));
+    assert_eq!(a.elementwise_min(&b).unwrap(), Matrix::from(vec![vec![0.5f64, -5.0f64], vec![2.5f64, 0.0f64]]));
+
+    // Running maximum over several matrices
+    let stack = [a.clone(), b.clone(), Matrix::filled(2, 2, 2.0f64)];
+    let max = stack.iter().skip(1).fold(stack[0].clone(), |acc, m| acc.elementwise_max(m).unwrap());
+    assert_eq!(max, Matrix::from(vec![vec![2.0f64, 2.0f64], vec![2.5f64, 3.0f64]]));
+
+    assert_eq!(a.elementwise_min(&Matrix::new(2, 3)), Err(MatxError::SizeError((2, 2), (2, 3))));
+}
+
+
+#[test]
+fn max_pool() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 5, 2, 0],
+        vec![3, 4, 8, 1],
+        vec![0, 0, 7, 6],
+        vec![9, 2, 3, 4]
+    ]);
+
+    assert_eq!(a.max_pool(2, 2), Matrix::from(vec![vec![5, 8], vec![9, 7]]));
+
+    // Overlapping windows
+    assert_eq!(a.max_pool(2, 1), Matrix::from(vec![vec![5, 8, 8], vec![4, 8, 8], vec![9, 7, 7]]));
+
+    // Incomplete windows are dropped
+    assert_eq!(a.max_pool(3, 2), Matrix::from(vec![vec![8]]));
+    assert_eq!(a.max_pool(5, 1), Matrix::new(0, 0));
+}
+
+
+#[test]
+fn avg_pool() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 5, 2, 0],
+        vec![3, 4, 8, 1],
+        vec![0, 0, 7, 6],
+        vec![9, 2, 3, 4]
+    ]);
+
+    assert_eq!(a.avg_pool(2, 2), Matrix::from(vec![vec![3.25f64, 2.75f64], vec![2.75f64, 5.0f64]]));
+    assert_eq!(a.avg_pool(4, 1), Matrix::from(vec![vec![55.0f64 / 16.0f64]]));
+    assert_eq!(a.avg_pool(1, 3), Matrix::from(vec![vec![1.0f64, 0.0f64], vec![9.0f64, 4.0f64]]));
+}
+
+
+#[test]
+fn set_submatrix() {
+
+    let mut a = Matrix::<i32>::new(4, 4);
+    let block = Matrix::<i32>::from(vec![vec![1, 2], vec![3, 4]]);
+
+    a.set_submatrix(1, 2, &block).unwrap();
+
+    assert_eq!(a, Matrix::from(vec![
+        vec![0, 0, 0, 0],
+        vec![0, 0, 1, 2],
+        vec![0, 0, 3, 4],
+        vec![0, 0, 0, 0]
+    ]));
+
+    assert_eq!(a.set_submatrix(3, 0, &block), Err(MatxError::SizeError((4, 4), (5, 2))));
+    assert_eq!(a.set_submatrix(0, 3, &block), Err(MatxError::SizeError((4, 4), (2, 5))));
+    assert_eq!(a.set_submatrix(usize::MAX, 0, &block), Err(MatxError::SizeError((4, 4), (usize::MAX, 2))));
+    assert_eq!(a.set_submatrix(0, usize::MAX - 1, &block), Err(MatxError::SizeError((4, 4), (2, usize::MAX))));
+
+    // Nothing is written on error
+    assert_eq!(a.count_where(|x| *x != 0), 4);
+}
+
+
+#[test]
+fn div_scalar_checked() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![10, -7],
+        vec![3, 0]
+    ]);
+
+    assert_eq!(a.div_scalar_checked(3), Ok(Matrix::from(vec![vec![3, -2], vec![1, 0]])));
+    assert_eq!(a.div_scalar_checked(0), Err(MatxError::DivideByZero));
+
+    assert_eq!(Matrix::<f64>::ones(2, 2).div_scalar_checked(0.0f64), Err(MatxError::DivideByZero));
+    assert_eq!(Matrix::<f64>::ones(2, 2).div_scalar_checked(4.0f64), Ok(Matrix::filled(2, 2, 0.25f64)));
+}
+
+
+#[test]
+fn rem() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![7, 12, -5],
+        vec![0, 9, 4]
+    ]);
+
+    assert_eq!(a.clone() % 4, Matrix::from(vec![vec![3, 0, -1], vec![0, 1, 0]]));
+
+    let b = Matrix::<i32>::from(vec![
+        vec![2, 5, 3],
+        vec![7, 10, 4]
+    ]);
+
+    assert_eq!(a.clone() % b, Ok(Matrix::from(vec![vec![1, 2, -2], vec![0, 9, 0]])));
+    assert_eq!(a.clone() % a.transpose(), Err(MatxError::SizeError((2, 3), (3, 2))));
+
+    // Wrapping coordinates around a 5-wide grid
+    let wrapped = Matrix::<f64>::from(vec![vec![6.5f64, 5.0f64]]) % 5.0f64;
+    assert_eq!(wrapped, Matrix::from(vec![vec![1.5f64, 0.0f64]]));
+}
+
+
+#[test]
+fn abs_signum() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![-3, 0, 7],
+        vec![12, -1, 0]
+    ]);
+
+    assert_eq!(a.abs(), Matrix::from(vec![vec![3, 0, 7], vec![12, 1, 0]]));
+    assert_eq!(a.signum(), Matrix::from(vec![vec![-1, 0, 1], vec![1, -1, 0]]));
+
+    let b = Matrix::<f64>::from(vec![vec![-2.5f64, 0.5f64, -0.0f64]]);
+
+    assert_eq!(b.abs(), Matrix::from(vec![vec![2.5f64, 0.5f64, 0.0f64]]));
+    assert_eq!(b.signum().row(0).unwrap()[..2], [-1.0f64, 1.0f64]);
+}
+
+
+#[test]
+fn sqrt_exp_ln() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![0, 1, 4],
+        vec![9, 16, -4]
+    ]);
+
+    let sqrt = a.sqrt();
+    assert_eq!(sqrt.row(0).unwrap(), vec![0.0f64, 1.0f64, 2.0f64]);
+    assert_eq!(sqrt.row(1).unwrap()[..2], [3.0f64, 4.0f64]);
+    assert!(sqrt.get(1, 2).unwrap().is_nan());
+
+    let b = Matrix::<f64>::from(vec![vec![0.0f64, 1.0f64, -1.0f64, 2.0f64.ln()]]);
+    assert!(b.exp().approx_eq(&Matrix::from(vec![vec![1.0f64, std::f64::consts::E, 1.0f64 / std::f64::consts::E, 2.0f64]]), 1e-12));
+
+    let ln = a.ln();
+    assert_eq!(ln.get(0, 0).unwrap(), f64::NEG_INFINITY);
+    assert_eq!(ln.get(0, 1).unwrap(), 0.0f64);
+    assert!((ln.get(0, 2).unwrap() - 2.0f64 * 2.0f64.ln()).abs() < 1e-12);
+    assert!(ln.get(1, 2).unwrap().is_nan());
+
+    // ln and exp cancel out
+    assert!(b.exp().ln().approx_eq(&b, 1e-12));
+}
+
+
+#[test]
+fn normalize_rows_l2() {
+
+    let a = Matrix::<f64>::from(vec![
+        vec![1.0f64, -2.0f64, 2.0f64],
+        vec![0.0f64, 0.0f64, 0.0f64],
+        vec![0.5f64, 7.0f64, -3.0f64]
+    ]);
+
+    let n = a.normalize_rows_l2();
+
+    assert!(n.row(0).unwrap().iter().zip([1.0f64, -2.0f64, 2.0f64]).all(|(x, y)| (x - y / 3.0f64).abs() < 1e-12));
+    assert_eq!(n.row(1).unwrap(), vec![0.0f64; 3]);
+
+    for i in [0, 2] {
+        let norm = n.row(i).unwrap().iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+        assert!((norm - 1.0f64).abs() < 1e-12);
+    }
+
+    assert_eq!(Matrix::<i32>::from(vec![vec![0, 5]]).normalize_rows_l2(), Matrix::from(vec![vec![0.0f64, 1.0f64]]));
+}
+
+
+#[test]
+fn push_row() {
+
+    let mut a = Matrix::<i32>::with_capacity(3, 4);
+    assert_eq!((a.rows, a.cols), (3, 4));
+    assert_eq!(a, Matrix::new(3, 4));
+    assert_eq!(a.as_flat().len(), 12);
+
+    a.fill(7);
+    a.set(1, 2, 3).unwrap();
+    assert_eq!(a.get(2, 3).unwrap(), 1);
+    assert_eq!(a.count_where(|x| *x == 7), 11);
+
+    // Growing the matrix row by row
+    let mut b = Matrix::<i32>::with_capacity(0, 4);
+
+    for i in 0..3 {
+        b.push_row((0..4).map(|j| i*4 + j).collect()).unwrap();
+    }
+
+    assert_eq!(b, Matrix::try_new(3, 4, (0..12).collect()).unwrap());
+
+    assert_eq!(b.push_row(vec![0; 5]), Err(MatxError::SizeError((1, 4), (1, 5))));
+    assert_eq!(b.rows, 3);
+
+    // A 0*0 matrix takes the size of its first row
+    let mut c = Matrix::<i32>::new(0, 0);
+    c.push_row(vec![1, 2]).unwrap();
+    c.push_row(vec![3, 4]).unwrap();
+    assert_eq!(c, Matrix::from(vec![vec![1, 2], vec![3, 4]]));
+}
+
+
+#[test]
+fn remove() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 9]
+    ]);
+
+    let mut b = a.clone();
+    b.remove_row(1).unwrap();
+    assert_eq!(b, Matrix::from(vec![vec![1, 2, 3], vec![7, 8, 9]]));
+
+    b.remove_col(2).unwrap();
+    assert_eq!(b, Matrix::from(vec![vec![1, 2], vec![7, 8]]));
+
+    let mut c = a.clone();
+    c.remove_col(0).unwrap();
+    assert_eq!(c, Matrix::from(vec![vec![2, 3], vec![5, 6], vec![8, 9]]));
+
+    assert_eq!(c.remove_row(3), Err(MatxError::IndexError(3, 3)));
+    assert_eq!(c.remove_col(2), Err(MatxError::IndexError(2, 2)));
+
+    // Removing everything
+    c.remove_col(0).unwrap();
+    c.remove_col(0).unwrap();
+    assert_eq!((c.rows, c.cols), (3, 0));
+    assert!(c.as_flat().is_empty());
+}
+
+
+#[test]
+fn insert() {
+
+    let a = Matrix::<i32>::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6]
+    ]);
+
+    for (at, expected) in [
+        (0, vec![vec![0, 0, 0], vec![1, 2, 3], vec![4, 5, 6]]),
+        (1, vec![vec![1, 2, 3], vec![0, 0, 0], vec![4, 5, 6]]),
+        (2, vec![vec![1, 2, 3], vec![4, 5, 6], vec![0, 0, 0]])
+    ] {
+        let mut b = a.clone();
+        b.insert_row(at, vec![0; 3]).unwrap();
+        assert_eq!(b, Matrix::from(expected));
+    }
+
+    let mut c = a.clone();
+    c.insert_col(0, vec![7, 8]).unwrap();
+    c.insert_col(2, vec![9, 10]).unwrap();
+    c.insert_col(5, vec![11, 12]).unwrap();
+    assert_eq!(c, Matrix::from(vec![vec![7, 1, 9, 2, 3, 11], vec![8, 4, 10, 5, 6, 12]]));
+
+    let mut d = a.clone();
+    assert_eq!(d.insert_row(3, vec![0; 3]), Err(MatxError::IndexError(3, 2)));
+    assert_eq!(d.insert_row(1, vec![0; 2]), Err(MatxError::SizeError((1, 3), (1, 2))));
+    assert_eq!(d.insert_col(4, vec![0; 2]), Err(MatxError::IndexError(4, 3)));
+    assert_eq!(d.insert_col(1, vec![0; 3]), Err(MatxError::SizeError((2, 1), (3, 1))));
+    assert_eq!(d, a);
+
+    // Inserting then removing gives back the original matrix
+    d.insert_col(1, vec![0; 2]).unwrap();
+    d.remove_col(1).unwrap();
+    assert_eq!(d, a);
+
+    let mut e = Matrix::<i32>::new(0, 0);
+    e.insert_col(0, vec![1, 2]).unwrap();
+    assert_eq!(e, Matrix::from(vec![vec![1], vec![2]]));
+}